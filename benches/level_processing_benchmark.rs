@@ -1,7 +1,7 @@
 use base64::{engine::general_purpose::URL_SAFE, Engine};
 use criterion::{criterion_group, criterion_main, Criterion};
 use dash_rs::{
-    model::level::{Level, LevelData},
+    model::level::{stream::ObjectStream, Level, LevelData},
     GJFormat, Thunk,
 };
 use flate2::read::GzDecoder;
@@ -71,11 +71,30 @@ pub fn decoding_spacial_rend_benchmark(c: &mut Criterion) {
     });
 }
 
+pub fn streaming_spacial_rend_benchmark(c: &mut Criterion) {
+    let response = read_to_string("./benches/data/40292513_special_rend_gjdownload_response").unwrap();
+
+    c.bench_function("stream spacial rend", |b| {
+        b.iter(|| {
+            let level: Level<LevelData> = Level::from_gj_str(&response).unwrap();
+            match level.level_data.level_data {
+                Thunk::Unprocessed(unprocessed) => {
+                    for object in ObjectStream::new(&unprocessed).unwrap() {
+                        object.unwrap();
+                    }
+                },
+                Thunk::Processed(_) => unreachable!(),
+            }
+        })
+    });
+}
+
 criterion_group!(
     benches,
     ocular_miracle_benchmark,
     spacial_rend_benchmark,
     decoding_spacial_rend_benchmark,
-    decoding_ocular_miracle_benchmark
+    decoding_ocular_miracle_benchmark,
+    streaming_spacial_rend_benchmark
 );
 criterion_main!(benches);
@@ -0,0 +1,144 @@
+//! Generates the object id catalogue in `model::level::object::ids` from `ids.yaml`
+
+use serde::Deserialize;
+use std::{
+    collections::HashSet,
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CATALOGUE: &str = "src/model/level/object/ids.yaml";
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Category {
+    Decoration,
+    Solid,
+    Hazard,
+    Trigger,
+    Portal,
+    Interactive,
+}
+
+impl Category {
+    fn default_shape(self) -> Option<Shape> {
+        match self {
+            Category::Decoration | Category::Trigger => None,
+            Category::Solid | Category::Interactive => Some(Shape::Rectangle([30.0, 30.0])),
+            Category::Hazard => Some(Shape::Rectangle([6.0, 12.0])),
+            Category::Portal => Some(Shape::Rectangle([34.0, 86.0])),
+        }
+    }
+
+    fn default_hitbox(self) -> Hitbox {
+        match self {
+            Category::Decoration | Category::Trigger => Hitbox::None,
+            Category::Solid => Hitbox::Solid,
+            Category::Hazard => Hitbox::Hazard,
+            Category::Portal | Category::Interactive => Hitbox::Special,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Hitbox {
+    None,
+    Solid,
+    Hazard,
+    Special,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Shape {
+    Rectangle([f32; 2]),
+    Circle(f32),
+    Slope([f32; 2]),
+}
+
+impl Shape {
+    fn to_rust(self) -> String {
+        match self {
+            Shape::Rectangle([width, height]) => format!("HitboxShape::Rectangle {{ width: {:?}, height: {:?} }}", width, height),
+            Shape::Circle(radius) => format!("HitboxShape::Circle {{ radius: {:?} }}", radius),
+            Shape::Slope([width, height]) => format!("HitboxShape::Slope {{ width: {:?}, height: {:?} }}", width, height),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Entry {
+    id: u16,
+    name: String,
+    constant: Option<String>,
+    category: Category,
+    hitbox: Option<Hitbox>,
+    #[serde(default = "default_counts_toward_limit")]
+    counts_toward_limit: bool,
+    shape: Option<Shape>,
+    #[serde(default)]
+    offset: [f32; 2],
+}
+
+fn default_counts_toward_limit() -> bool {
+    true
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", CATALOGUE);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let yaml = fs::read_to_string(CATALOGUE).unwrap_or_else(|err| panic!("failed to read {}: {}", CATALOGUE, err));
+    let mut entries: Vec<Entry> = serde_yaml::from_str(&yaml).unwrap_or_else(|err| panic!("failed to parse {}: {}", CATALOGUE, err));
+
+    let mut generated = String::new();
+    let mut constants = HashSet::new();
+
+    for entry in &entries {
+        if let Some(ref constant) = entry.constant {
+            if !constants.insert(constant) {
+                panic!("constant {} is defined twice in {}", constant, CATALOGUE)
+            }
+
+            writeln!(generated, "/// {}\npub const {}: u16 = {};", entry.name, constant, entry.id).unwrap();
+        }
+    }
+
+    entries.sort_by_key(|entry| entry.id);
+
+    if let Some(pair) = entries.windows(2).find(|pair| pair[0].id == pair[1].id) {
+        panic!("object id {} is listed twice in {}", pair[0].id, CATALOGUE)
+    }
+
+    writeln!(generated, "\nstatic CATALOGUE: &[ObjectInfo] = &[").unwrap();
+
+    for entry in &entries {
+        writeln!(
+            generated,
+            "    ObjectInfo {{ id: {}, name: {:?}, category: ObjectCategory::{:?}, hitbox: HitboxType::{:?}, counts_toward_limit: {}, \
+             shape: {}, hitbox_offset: ({:?}, {:?}) }},",
+            entry.id,
+            entry.name,
+            entry.category,
+            entry.hitbox.unwrap_or_else(|| entry.category.default_hitbox()),
+            entry.counts_toward_limit,
+            match entry.shape.or_else(|| entry.category.default_shape()) {
+                Some(shape) => format!("Some({})", shape.to_rust()),
+                None => "None".to_string(),
+            },
+            entry.offset[0],
+            entry.offset[1]
+        )
+        .unwrap();
+    }
+
+    writeln!(generated, "];").unwrap();
+
+    let out: PathBuf = Path::new(&env::var_os("OUT_DIR").unwrap()).join("ids.rs");
+
+    fs::write(out, generated).unwrap();
+}
@@ -19,6 +19,11 @@ pub enum FieldMapping {
     ///
     /// These get initialized to [`Default::default`] during deserialization.
     NoIndex { field: Ident },
+
+    /// An API field that collects all indices not mapped to any other field
+    ///
+    /// These are passed to serde as `#[serde(flatten)]` fields.
+    Flatten(Flatten),
 }
 
 pub enum LitIndex {
@@ -120,10 +125,69 @@ impl OneToOne {
     }
 }
 
+pub struct Flatten {
+    /// The API field which collects the unmapped indices
+    pub field: Ident,
+
+    /// The type of the API field
+    pub api_type: Type,
+}
+
+impl Flatten {
+    fn internal_name(&self) -> Ident {
+        format_ident!("flattened_{}", self.field)
+    }
+
+    fn field_tokens(&self, ty: Type) -> proc_macro2::TokenStream {
+        let field_name = self.internal_name();
+
+        quote! {
+            #[serde(flatten)]
+            pub #field_name: #ty,
+        }
+    }
+
+    pub fn ser_field_tokens(&self, lifetime: &Lifetime) -> proc_macro2::TokenStream {
+        let api_type = &self.api_type;
+
+        self.field_tokens(parse_quote! {
+            <#api_type as crate::serde::InternalProxy>::SerializeProxy<#lifetime>
+        })
+    }
+
+    pub fn de_field_tokens(&self) -> proc_macro2::TokenStream {
+        let api_type = &self.api_type;
+
+        self.field_tokens(parse_quote! {
+            <#api_type as crate::serde::InternalProxy>::DeserializeProxy
+        })
+    }
+
+    pub fn serialize(&self) -> proc_macro2::TokenStream {
+        let field_name = self.internal_name();
+        let field = &self.field;
+
+        quote! {
+            #field_name: self.#field.to_serialize_proxy(),
+        }
+    }
+
+    pub fn deserialize(&self) -> proc_macro2::TokenStream {
+        let field_name = self.internal_name();
+        let field = &self.field;
+        let api_type = &self.api_type;
+
+        quote! {
+            #field: <#api_type>::from_deserialize_proxy(internal.#field_name),
+        }
+    }
+}
+
 impl FieldMapping {
     pub fn ser_field_tokens(&self, lifetime: &Lifetime) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.ser_field_tokens(lifetime),
+            FieldMapping::Flatten(inner) => inner.ser_field_tokens(lifetime),
             FieldMapping::NoIndex { .. } => quote!(),
         }
     }
@@ -131,6 +195,7 @@ impl FieldMapping {
     pub fn de_field_tokens(&self) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.de_field_tokens(),
+            FieldMapping::Flatten(inner) => inner.de_field_tokens(),
             FieldMapping::NoIndex { .. } => quote!(),
         }
     }
@@ -138,6 +203,7 @@ impl FieldMapping {
     pub fn serialize(&self) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.serialize(),
+            FieldMapping::Flatten(inner) => inner.serialize(),
             FieldMapping::NoIndex { .. } => quote!(),
         }
     }
@@ -145,6 +211,7 @@ impl FieldMapping {
     pub fn deserialize(&self) -> TokenStream {
         match self {
             FieldMapping::OneToOne(inner) => inner.deserialize(),
+            FieldMapping::Flatten(inner) => inner.deserialize(),
             FieldMapping::NoIndex { field } => quote! {
                 #field: Default::default(),
             },
@@ -161,6 +228,7 @@ enum FieldMappingBuilder {
         passthrough: Vec<TokenStream>,
     },
     NoIndex,
+    Flatten,
 }
 
 impl FieldMappingBuilder {
@@ -191,6 +259,14 @@ impl FieldMappingBuilder {
         true
     }
 
+    fn flatten(&mut self) -> bool {
+        match std::mem::take(self) {
+            FieldMappingBuilder::Initial => *self = FieldMappingBuilder::Flatten,
+            _ => return false,
+        }
+        true
+    }
+
     fn with_passthrough(&mut self, tokens: TokenStream) -> bool {
         match std::mem::take(self) {
             FieldMappingBuilder::Initial => {
@@ -203,7 +279,7 @@ impl FieldMappingBuilder {
                 passthrough.push(tokens);
                 *self = FieldMappingBuilder::OneToOne { index, passthrough }
             },
-            FieldMappingBuilder::NoIndex => return false,
+            FieldMappingBuilder::NoIndex | FieldMappingBuilder::Flatten => return false,
         }
         true
     }
@@ -231,6 +307,7 @@ impl TryFrom<Field> for FieldMapping {
                 DashAttribute::Index(idx) => builder.with_index(idx),
                 DashAttribute::PassthroughToSerde(tokens) => builder.with_passthrough(tokens),
                 DashAttribute::NoIndex => builder.no_index(),
+                DashAttribute::Flatten => builder.flatten(),
             };
 
             if !build_success {
@@ -253,6 +330,7 @@ impl TryFrom<Field> for FieldMapping {
             })),
             FieldMappingBuilder::OneToOne { index: None, .. } => Err(Error::new_spanned(field, "missing #[dash(index = ...)] attribute")),
             FieldMappingBuilder::NoIndex => Ok(FieldMapping::NoIndex { field }),
+            FieldMappingBuilder::Flatten => Ok(FieldMapping::Flatten(Flatten { field, api_type })),
         }
     }
 }
@@ -260,6 +338,7 @@ impl TryFrom<Field> for FieldMapping {
 enum DashAttribute {
     Index(LitIndex),
     NoIndex,
+    Flatten,
     PassthroughToSerde(TokenStream),
 }
 
@@ -273,6 +352,11 @@ impl Parse for DashAttribute {
 
                 return Ok(DashAttribute::NoIndex);
             }
+            if key == "flatten" {
+                input.advance_to(&fork);
+
+                return Ok(DashAttribute::Flatten);
+            }
             if key == "index" {
                 let _ = fork.parse::<Token![=]>()?;
                 let lookahead = fork.lookahead1();
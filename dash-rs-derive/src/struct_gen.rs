@@ -1,6 +1,6 @@
 use proc_macro2::{Ident, Span};
 use quote::{format_ident, quote, ToTokens};
-use syn::{Generics, Lifetime, LifetimeParam};
+use syn::{GenericParam, Generics, Lifetime, LifetimeParam};
 
 use crate::field::FieldMapping;
 
@@ -24,13 +24,20 @@ impl InternalStruct {
 
     fn ser_struct(&self) -> proc_macro2::TokenStream {
         let name = self.serialize_struct_name();
-        let static_lifetime = Lifetime::new("'static", Span::call_site());
+        // If the API struct has no lifetime, the serialization proxies might still borrow from it
+        // (e.g. for flattened fields), so introduce an artificial one.
+        let mut generics = self.generics.clone();
         let lifetime = match self.lifetime {
-            Some(ref lifetime) => &lifetime.lifetime,
-            None => &static_lifetime,
+            Some(ref lifetime) => lifetime.lifetime.clone(),
+            None => {
+                let artificial_lifetime = Lifetime::new("'__dash", Span::call_site());
+                generics
+                    .params
+                    .insert(0, GenericParam::Lifetime(LifetimeParam::new(artificial_lifetime.clone())));
+                artificial_lifetime
+            },
         };
-        let fields = self.fields.iter().map(|ifield| ifield.ser_field_tokens(lifetime));
-        let generics = &self.generics;
+        let fields = self.fields.iter().map(|ifield| ifield.ser_field_tokens(&lifetime));
 
         quote! {
             #[derive(Serialize)]
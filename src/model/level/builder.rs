@@ -0,0 +1,156 @@
+//! Module containing [`LevelBuilder`], which assembles levels from code
+
+use crate::{
+    model::level::{metadata::LevelMetadata, object::LevelObject, LevelProcessError, Objects},
+    GJFormat, Thunk,
+};
+use base64::{engine::general_purpose::URL_SAFE, Engine};
+use flate2::{
+    read::{GzEncoder, ZlibEncoder},
+    Compression,
+};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, io::Read};
+
+/// The compression schemes the game accepts for level strings
+///
+/// The game detects the scheme used when loading a level, so both work equally well. Older levels
+/// were compressed using zlib, newer ones use gzip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CompressionScheme {
+    #[default]
+    Gzip,
+    Zlib,
+}
+
+/// Builder for putting a level together from code
+///
+/// ```
+/// # use dash_rs::model::level::{builder::LevelBuilder, object::LevelObject};
+/// let level_string = LevelBuilder::new()
+///     .object(LevelObject::new(1, 15.0, 15.0))
+///     .object(LevelObject::new(8, 45.0, 15.0))
+///     .encode()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelBuilder {
+    meta: LevelMetadata,
+    objects: Vec<LevelObject>,
+    scheme: CompressionScheme,
+    level: u32,
+}
+
+impl Default for LevelBuilder {
+    fn default() -> Self {
+        LevelBuilder::new()
+    }
+}
+
+impl LevelBuilder {
+    /// Creates a builder for an empty level with a default header, which will be compressed the
+    /// same way [`Objects`] are (gzip, maximum compression level)
+    pub fn new() -> LevelBuilder {
+        LevelBuilder {
+            meta: LevelMetadata::default(),
+            objects: Vec::new(),
+            scheme: CompressionScheme::Gzip,
+            level: 9,
+        }
+    }
+
+    /// Creates a builder containing the header and objects of an existing level
+    pub fn from_objects(objects: Objects) -> LevelBuilder {
+        LevelBuilder {
+            meta: objects.meta,
+            objects: objects.objects,
+            ..LevelBuilder::new()
+        }
+    }
+
+    /// Sets the header of the level
+    pub fn meta(mut self, meta: LevelMetadata) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    /// Provides mutable access to the header of the level, for changing individual settings
+    pub fn meta_mut(&mut self) -> &mut LevelMetadata {
+        &mut self.meta
+    }
+
+    /// Appends an object to the level
+    pub fn object(mut self, object: LevelObject) -> Self {
+        self.objects.push(object);
+        self
+    }
+
+    /// Appends all given objects to the level
+    pub fn objects(mut self, objects: impl IntoIterator<Item = LevelObject>) -> Self {
+        self.objects.extend(objects);
+        self
+    }
+
+    /// Appends an object to the level, without consuming the builder
+    pub fn push(&mut self, object: LevelObject) {
+        self.objects.push(object)
+    }
+
+    /// Sets the compression scheme to use when encoding the level
+    pub fn compression(mut self, scheme: CompressionScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Sets the compression level to use when encoding the level, ranging from `0` (no compression)
+    /// to `9` (best compression). Values above `9` are treated as `9`.
+    pub fn compression_level(mut self, level: u32) -> Self {
+        self.level = level.min(9);
+        self
+    }
+
+    /// Finishes building, returning the level's header and objects
+    pub fn build(self) -> Objects {
+        Objects {
+            meta: self.meta,
+            objects: self.objects,
+        }
+    }
+
+    /// Encodes the level into the base64 encoded, compressed level string the game expects
+    pub fn encode(&self) -> Result<String, LevelProcessError> {
+        encode(&self.meta, &self.objects, self.scheme, self.level)
+    }
+
+    /// Encodes the level into a [`Thunk`] suitable for [`LevelData::level_data`](crate::model::level::LevelData::level_data)
+    ///
+    /// The thunk is unprocessed, so it is written out exactly as encoded by this builder.
+    pub fn into_thunk(self) -> Result<Thunk<'static, Objects>, LevelProcessError> {
+        Ok(Thunk::Unprocessed(Cow::Owned(self.encode()?)))
+    }
+}
+
+/// Serializes, compresses and base64 encodes the given level
+pub(crate) fn encode(
+    meta: &LevelMetadata, objects: &[LevelObject], scheme: CompressionScheme, level: u32,
+) -> Result<String, LevelProcessError> {
+    let mut bytes = Vec::new();
+
+    meta.write_gj(&mut bytes)?;
+
+    bytes.push(b';');
+
+    for object in objects {
+        object.write_gj(&mut bytes)?;
+        bytes.push(b';');
+    }
+
+    let mut compressed = Vec::new();
+
+    match scheme {
+        CompressionScheme::Gzip => GzEncoder::new(&bytes[..], Compression::new(level)).read_to_end(&mut compressed)?,
+        CompressionScheme::Zlib => ZlibEncoder::new(&bytes[..], Compression::new(level)).read_to_end(&mut compressed)?,
+    };
+
+    Ok(URL_SAFE.encode(compressed))
+}
@@ -0,0 +1,175 @@
+//! Module for computing a canonical form of a level's content, which allows detecting copies of a
+//! level independently of how its level string happens to be laid out
+//!
+//! Two level strings describing the same level can differ in the order of their objects, in the
+//! order of keys within an object, in how floats are formatted, and in whether keys holding their
+//! default value are written at all. The canonical form removes all of these differences:
+//! - Objects and headers are parsed into the typed model and serialized again, which drops
+//!   default-valued keys and formats all floats the same way.
+//! - All numeric values are rounded to three decimal places, including those nested in lists
+//!   such as HSV values, color channels or guidelines. Rotations are brought into the range
+//!   `[0, 360)`.
+//! - Group lists are sorted and deduplicated.
+//! - Keys within each object and the objects themselves are sorted.
+//! - Color channels are sorted by id, and editor-only state (the selected color page) is dropped.
+
+use crate::model::level::{diff::properties, object::LevelObject, LevelProcessError, Objects};
+use std::{borrow::Cow, collections::BTreeMap};
+
+/// Keys whose values are not normalized. The group list (57) and the steps of sequence triggers
+/// (435) use `.` as a separator, and text objects (31) store base64.
+const VERBATIM_KEYS: [&str; 3] = ["31", "57", "435"];
+
+/// Rounds a serialized number to three decimal places. Values that aren't numbers are returned
+/// unchanged.
+fn normalize_number(value: &str) -> Cow<'_, str> {
+    // Rust's float parser also accepts things like "inf" or "1e5", which RobTop's format never
+    // contains as numbers
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit() || b == b'.' || b == b'-') {
+        return Cow::Borrowed(value);
+    }
+
+    match value.parse::<f64>() {
+        // Avoids -0 and 0 having different canonical forms
+        Ok(number) if (number * 1000.0).round() == 0.0 => Cow::Borrowed("0"),
+        Ok(number) => Cow::Owned(((number * 1000.0).round() / 1000.0).to_string()),
+        Err(_) => Cow::Borrowed(value),
+    }
+}
+
+/// Normalizes every number in a serialized value. Nested lists are normalized element-wise, which
+/// covers HSV values (separated by `a`), color channels (`_` and `|`) and guidelines (`~`).
+fn normalize(value: &str) -> String {
+    let mut normalized = String::with_capacity(value.len());
+    let mut start = 0;
+
+    for (index, separator) in value.match_indices(['a', '_', '|', '~']) {
+        normalized.push_str(&normalize_number(&value[start..index]));
+        normalized.push_str(separator);
+        start = index + separator.len();
+    }
+
+    normalized.push_str(&normalize_number(&value[start..]));
+    normalized
+}
+
+/// Joins key-value pairs back into RobTop's format, normalizing all values
+fn join(properties: BTreeMap<String, String>, delimiter: &str) -> String {
+    properties
+        .into_iter()
+        .flat_map(|(key, value)| {
+            let value = if VERBATIM_KEYS.contains(&key.as_str()) {
+                value
+            } else {
+                normalize(&value)
+            };
+
+            [key, value]
+        })
+        .collect::<Vec<_>>()
+        .join(delimiter)
+}
+
+/// 64 bit FNV-1a. Unlike [`std::hash::Hasher`] implementations, its output is guaranteed not to
+/// change between Rust versions.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl LevelObject {
+    /// The canonical form of this object, see the [module level documentation](self)
+    pub fn canonical_form(&self) -> Result<String, LevelProcessError> {
+        let mut object = self.clone();
+
+        object.rotation = object.rotation.rem_euclid(360.0);
+        object.groups.sort_unstable();
+        object.groups.dedup();
+
+        Ok(join(properties(&object)?, ","))
+    }
+}
+
+impl Objects {
+    /// The canonical forms of all objects in this level, sorted
+    fn canonical_objects(&self) -> Result<Vec<String>, LevelProcessError> {
+        let mut objects = self
+            .objects
+            .iter()
+            .map(LevelObject::canonical_form)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        objects.sort_unstable();
+
+        Ok(objects)
+    }
+
+    /// Computes the canonical form of this level, see the [module level documentation](self)
+    ///
+    /// The canonical form is itself a valid (decompressed) level string.
+    pub fn canonical_form(&self) -> Result<String, LevelProcessError> {
+        let mut meta = self.meta.clone();
+
+        meta.color_channels.sort_by_key(|channel| channel.id);
+        meta.color_page = 0;
+
+        for channel in &mut meta.color_channels {
+            channel.unknown_properties.clear_layout();
+        }
+
+        let mut canonical = join(properties(&meta)?, ",");
+
+        canonical.push(';');
+
+        for object in self.canonical_objects()? {
+            canonical.push_str(&object);
+            canonical.push(';');
+        }
+
+        Ok(canonical)
+    }
+
+    /// Computes a hash of the [canonical form](Objects::canonical_form) of this level
+    ///
+    /// Levels with the same content have the same hash, regardless of how their level strings are
+    /// laid out. The hash is stable, so it can be stored and compared across program runs and
+    /// versions of this library.
+    pub fn content_hash(&self) -> Result<u64, LevelProcessError> {
+        Ok(fnv1a(self.canonical_form()?.as_bytes()))
+    }
+
+    /// Computes how similar the objects of this level are to those of another level, as a value
+    /// between `0.0` (no objects in common) and `1.0` (the same objects)
+    ///
+    /// Levels are compared as multisets of [canonical objects](LevelObject::canonical_form): the
+    /// score is the number of objects the levels have in common divided by the number of objects
+    /// in either of them. Level headers are not taken into account. Two empty levels are considered
+    /// identical.
+    pub fn similarity(&self, other: &Objects) -> Result<f32, LevelProcessError> {
+        let ours = self.canonical_objects()?;
+        let theirs = other.canonical_objects()?;
+
+        if ours.is_empty() && theirs.is_empty() {
+            return Ok(1.0);
+        }
+
+        // Both lists are sorted, so the size of the multiset intersection can be computed by
+        // walking them in lockstep
+        let (mut i, mut j, mut common) = (0, 0, 0);
+
+        while i < ours.len() && j < theirs.len() {
+            match ours[i].cmp(&theirs[j]) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    common += 1;
+                    i += 1;
+                    j += 1;
+                },
+            }
+        }
+
+        Ok(common as f32 / (ours.len() + theirs.len() - common) as f32)
+    }
+}
@@ -0,0 +1,143 @@
+//! Module for locating the coins in a level and checking them against the coin information the
+//! servers report
+
+use crate::model::level::{
+    object::{interactive::Coin, ObjectData},
+    Level, LevelData, LevelProcessError, Objects,
+};
+use serde::{Deserialize, Serialize};
+
+/// The maximum amount of user coins the game counts in a single level
+pub const MAX_USER_COINS: usize = 3;
+
+/// A coin placed in a level, see [`Objects::coins`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CoinLocation {
+    /// The index of the coin in [`Objects::objects`]
+    pub index: usize,
+
+    pub coin: Coin,
+
+    pub x: f32,
+    pub y: f32,
+
+    /// The time, in seconds since the level started, at which the player reaches the coin
+    pub seconds: f32,
+}
+
+/// An inconsistency between the coins placed in a level and the coin information reported by the
+/// servers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoinIssue {
+    /// The amount of user coins in the level data differs from [`Level::coin_amount`]
+    CountMismatch { expected: u8, found: usize },
+
+    /// The level data contains more than [`MAX_USER_COINS`] user coins
+    TooManyCoins(usize),
+
+    /// The level data contains secret coins, which only count towards the player's stats in the
+    /// official levels
+    SecretCoins(usize),
+
+    /// The servers report the level's coins as verified, but there are no user coins in the level
+    /// data
+    VerifiedWithoutCoins,
+}
+
+/// The result of comparing the coins in a level's data with [`Level::coin_amount`] and
+/// [`Level::coins_verified`], see [`Objects::check_coins`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoinReport {
+    /// The amount of user coins found in the level data
+    pub user_coins: usize,
+
+    /// The amount of secret coins found in the level data
+    pub secret_coins: usize,
+
+    /// The amount of coins reported by the servers
+    pub coin_amount: u8,
+
+    /// Whether the servers report the level's coins as verified
+    pub coins_verified: bool,
+
+    pub issues: Vec<CoinIssue>,
+}
+
+impl CoinReport {
+    /// Returns `true` iff no inconsistencies were found
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Objects {
+    /// Lists all secret and user coins in this level, together with the time at which the player
+    /// reaches them, sorted by x position
+    pub fn coins(&self) -> Vec<CoinLocation> {
+        let timeline = self.timeline();
+        let mut coins: Vec<CoinLocation> = self
+            .objects
+            .iter()
+            .enumerate()
+            .filter_map(|(index, object)| match object.metadata {
+                ObjectData::Coin { coin } => Some(CoinLocation {
+                    index,
+                    coin,
+                    x: object.x,
+                    y: object.y,
+                    seconds: timeline.seconds_at(object.x),
+                }),
+                _ => None,
+            })
+            .collect();
+
+        coins.sort_by(|a, b| a.x.total_cmp(&b.x));
+        coins
+    }
+
+    /// Compares the coins in this level with the given coin information, as reported by the
+    /// servers in [`Level::coin_amount`] and [`Level::coins_verified`]
+    pub fn check_coins(&self, coin_amount: u8, coins_verified: bool) -> CoinReport {
+        let coins = self.coins();
+        let user_coins = coins.iter().filter(|location| location.coin == Coin::User).count();
+        let secret_coins = coins.len() - user_coins;
+        let mut issues = Vec::new();
+
+        if user_coins.min(MAX_USER_COINS) != coin_amount as usize {
+            issues.push(CoinIssue::CountMismatch {
+                expected: coin_amount,
+                found: user_coins,
+            });
+        }
+
+        if user_coins > MAX_USER_COINS {
+            issues.push(CoinIssue::TooManyCoins(user_coins));
+        }
+
+        if secret_coins > 0 {
+            issues.push(CoinIssue::SecretCoins(secret_coins));
+        }
+
+        if coins_verified && user_coins == 0 {
+            issues.push(CoinIssue::VerifiedWithoutCoins);
+        }
+
+        CoinReport {
+            user_coins,
+            secret_coins,
+            coin_amount,
+            coins_verified,
+            issues,
+        }
+    }
+}
+
+impl<'a, Song, User> Level<'a, LevelData<'a>, Song, User> {
+    /// Compares the coins in this level's data with [`Level::coin_amount`] and
+    /// [`Level::coins_verified`], processing the level data if necessary
+    pub fn check_coins(&mut self) -> Result<CoinReport, LevelProcessError> {
+        let (coin_amount, coins_verified) = (self.coin_amount, self.coins_verified);
+
+        Ok(self.level_data.level_data.process()?.check_coins(coin_amount, coins_verified))
+    }
+}
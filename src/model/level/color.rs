@@ -0,0 +1,319 @@
+//! Module containing structs related to coloring of levels and the objects inside them
+
+use crate::{
+    model::level::{LevelProcessError, UnknownProperties},
+    serde::{InternalProxy, Nested},
+    Dash, DeError, GJFormat, IndexedDeserializer, IndexedSerializer, SerError, ThunkProcessor,
+};
+use dash_rs_derive::Dash;
+use serde::{de::Error as _, Deserialize, Serialize};
+use std::{borrow::Cow, io::Write};
+
+/// Struct modelling a HSV (hue, saturation, value) modification that is applied to some color
+///
+/// ## GD Internals:
+/// In object strings, HSV values are encoded as list-like, `a`-separated strings of the form
+/// `hue a saturation a value a saturation_additive a value_additive`, e.g. `0a1a1.5a0a0`.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct HsvValue {
+    /// The hue shift, in degrees
+    pub hue: f32,
+
+    /// The saturation modification.
+    ///
+    /// If [`HsvValue::saturation_additive`] is `false`, this is a factor, otherwise it is added to
+    /// the saturation
+    pub saturation: f32,
+
+    /// The value (brightness) modification.
+    ///
+    /// If [`HsvValue::value_additive`] is `false`, this is a factor, otherwise it is added to the
+    /// value
+    pub value: f32,
+
+    /// Whether [`HsvValue::saturation`] is additive instead of multiplicative
+    pub saturation_additive: bool,
+
+    /// Whether [`HsvValue::value`] is additive instead of multiplicative
+    pub value_additive: bool,
+}
+
+impl Default for HsvValue {
+    fn default() -> Self {
+        HsvValue {
+            hue: 0.0,
+            saturation: 1.0,
+            value: 1.0,
+            saturation_additive: false,
+            value_additive: false,
+        }
+    }
+}
+
+impl<'de> Dash<'de> for HsvValue {
+    fn dash_deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HsvValue::deserialize(deserializer)
+    }
+
+    fn dash_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize(serializer)
+    }
+}
+
+impl<'de> GJFormat<'de> for HsvValue {
+    const DELIMITER: &'static str = "a";
+    const MAP_LIKE: bool = false;
+}
+
+impl InternalProxy for HsvValue {
+    type DeserializeProxy = Nested<HsvValue>;
+    type SerializeProxy<'a> = Nested<HsvValue>;
+
+    fn to_serialize_proxy(&self) -> Self::SerializeProxy<'_> {
+        Nested(*self)
+    }
+
+    fn from_deserialize_proxy(from: Self::DeserializeProxy) -> Self {
+        from.0
+    }
+}
+
+/// The id of the color channel used for a level's background
+pub const BACKGROUND_CHANNEL: u16 = 1000;
+/// The id of the color channel used for a level's ground
+pub const GROUND_CHANNEL: u16 = 1001;
+/// The id of the color channel used for the line drawn on top of a level's ground
+pub const LINE_CHANNEL: u16 = 1002;
+/// The id of the color channel used for 3D lines
+pub const THREE_D_LINE_CHANNEL: u16 = 1003;
+/// The id of the color channel used for objects that do not use any other color channel
+pub const OBJECT_CHANNEL: u16 = 1004;
+/// The id of the color channel used for a level's second ground
+pub const GROUND_2_CHANNEL: u16 = 1009;
+/// The id of a level's first middleground color channel
+pub const MIDDLEGROUND_CHANNEL: u16 = 1013;
+/// The id of a level's second middleground color channel
+pub const MIDDLEGROUND_2_CHANNEL: u16 = 1014;
+
+/// Struct modelling a single color channel of a level
+///
+/// Channels `1` to `999` can be freely used by objects, while channels `1000` and above are used
+/// by the game for special purposes (see for example [`BACKGROUND_CHANNEL`]).
+///
+/// ## GD Internals:
+/// Color channels are encoded as map-like, `_`-separated strings, e.g.
+/// `1_0_2_102_3_255_11_255_12_255_13_255_6_1001_7_1_15_1_18_0_8_1`.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Dash)]
+pub struct ColorChannel {
+    /// The id of this color channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `6`
+    #[dash(index = 6)]
+    #[dash(default)]
+    pub id: u16,
+
+    /// The red component of this channel's color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `1`
+    #[dash(index = 1)]
+    #[dash(default)]
+    pub red: u8,
+
+    /// The green component of this channel's color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `2`
+    #[dash(index = 2)]
+    #[dash(default)]
+    pub green: u8,
+
+    /// The blue component of this channel's color
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `3`
+    #[dash(index = 3)]
+    #[dash(default)]
+    pub blue: u8,
+
+    /// Whether this channel takes its color from one of the player's colors instead of
+    /// [`ColorChannel::red`], [`ColorChannel::green`] and [`ColorChannel::blue`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `4`
+    #[dash(index = 4)]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "crate::util::is_default")]
+    pub player_color: PlayerColor,
+
+    /// Whether this channel uses additive blending
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `5`
+    #[dash(index = 5)]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "crate::util::is_default")]
+    pub blending: bool,
+
+    /// The opacity of this channel, between `0.0` and `1.0`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `7`
+    #[dash(index = 7)]
+    #[dash(default = "one")]
+    pub opacity: f32,
+
+    /// The id of the color channel this channel copies its color from, or `0` if it does not copy
+    /// another channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    #[dash(index = 9)]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "crate::util::is_default")]
+    pub copied_channel: u16,
+
+    /// The HSV modification applied to the color copied from [`ColorChannel::copied_channel`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    #[dash(index = 10)]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "Option::is_none")]
+    pub copied_hsv: Option<HsvValue>,
+
+    /// Whether this channel also copies the opacity of [`ColorChannel::copied_channel`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `17`
+    #[dash(index = 17)]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "crate::util::is_default")]
+    pub copy_opacity: bool,
+
+    /// All key-value pairs of this channel that dash-rs does not map to any of the above fields,
+    /// in the order they appeared in
+    #[dash(flatten)]
+    pub unknown_properties: UnknownProperties,
+}
+
+impl<'de> GJFormat<'de> for ColorChannel {
+    const DELIMITER: &'static str = "_";
+    const MAP_LIKE: bool = true;
+
+    fn from_gj_str(input: &'de str) -> Result<Self, DeError<'de>> {
+        let mut channel = Self::dash_deserialize(&mut IndexedDeserializer::new(input, Self::DELIMITER, Self::MAP_LIKE))?;
+        let mut serialized = Vec::new();
+
+        channel
+            .dash_serialize(&mut IndexedSerializer::new(Self::DELIMITER, &mut serialized, Self::MAP_LIKE))
+            .map_err(DeError::custom)?;
+
+        // We only ever write valid UTF-8
+        let serialized = String::from_utf8(serialized).unwrap();
+        let written: Vec<_> = serialized.split(Self::DELIMITER).step_by(2).collect();
+        let mut split = input.split(Self::DELIMITER);
+
+        // Keys that are not written back despite being present were explicitly set to their
+        // default value
+        while let (Some(key), Some(value)) = (split.next(), split.next()) {
+            if !written.contains(&key) {
+                channel.unknown_properties.record_explicit_default(key, value)
+            }
+        }
+
+        channel.unknown_properties.record_layout(input, Self::DELIMITER);
+
+        Ok(channel)
+    }
+
+    fn write_gj<W: Write>(&self, writer: W) -> Result<(), SerError> {
+        let mut buffer = Vec::new();
+
+        self.dash_serialize(&mut IndexedSerializer::new(Self::DELIMITER, &mut buffer, Self::MAP_LIKE))?;
+        self.unknown_properties.write_in_layout(&buffer, Self::DELIMITER, writer)
+    }
+}
+
+fn one() -> f32 {
+    1.0
+}
+
+/// Enum modelling which player color (if any) a [`ColorChannel`] takes its color from
+///
+/// ## GD Internals:
+/// The game writes `-1` for channels using their own color. Any value other than `-1`, `1` and `2`
+/// (including `0`, which some levels contain) is kept as [`PlayerColor::Unknown`], so that it is
+/// written back unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PlayerColor {
+    /// The channel uses its own color
+    #[default]
+    None,
+    Player1,
+    Player2,
+    Unknown(i8),
+}
+
+impl From<i8> for PlayerColor {
+    fn from(value: i8) -> Self {
+        match value {
+            -1 => PlayerColor::None,
+            1 => PlayerColor::Player1,
+            2 => PlayerColor::Player2,
+            unknown => PlayerColor::Unknown(unknown),
+        }
+    }
+}
+
+impl From<PlayerColor> for i8 {
+    fn from(color: PlayerColor) -> Self {
+        match color {
+            PlayerColor::None => -1,
+            PlayerColor::Player1 => 1,
+            PlayerColor::Player2 => 2,
+            PlayerColor::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+crate::into_conversion!(PlayerColor, i8);
+
+/// [`ThunkProcessor`] for the list of [`ColorChannel`]s stored in a level's metadata section
+///
+/// ## GD Internals:
+/// The individual channels are separated by `|`, with a trailing `|` after the last channel.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone, Copy)]
+pub struct ColorChannels;
+
+impl ThunkProcessor for ColorChannels {
+    type Error = LevelProcessError;
+    type Output<'a> = Vec<ColorChannel>;
+
+    fn from_unprocessed(unprocessed: Cow<'_, str>) -> Result<Self::Output<'_>, Self::Error> {
+        unprocessed
+            .split_terminator('|')
+            .map(ColorChannel::from_gj_str)
+            .collect::<Result<_, _>>()
+            .map_err(|err| LevelProcessError::Deserialize(err.to_string()))
+    }
+
+    fn as_unprocessed<'b>(processed: &'b Self::Output<'_>) -> Result<Cow<'b, str>, Self::Error> {
+        let mut bytes = Vec::new();
+
+        for channel in processed {
+            channel.write_gj(&mut bytes)?;
+            bytes.push(b'|');
+        }
+
+        // We only ever write valid UTF-8
+        Ok(Cow::Owned(String::from_utf8(bytes).unwrap()))
+    }
+
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
+}
+
+crate::processed_conversion!(Vec<ColorChannel>, ColorChannels);
@@ -0,0 +1,182 @@
+//! Module containing [`LevelDiff`], describing the differences between two versions of a level
+
+use crate::{
+    model::level::{object::LevelObject, LevelProcessError, Objects},
+    GJFormat, IndexedSerializer,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// A single key whose value differs between two versions of an object or level header
+///
+/// Keys are given in RobTop's format (e.g. `"2"` for an object's x position or `"kA4"` for the
+/// starting speed), values as they would appear in the level string. A value of [`None`] means the
+/// key is absent in that version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyChange {
+    pub key: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// An object that exists in both versions of a level, but whose properties changed
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifiedObject<'a> {
+    /// The index of the object in the old version's [`Objects::objects`]
+    pub old_index: usize,
+
+    /// The index of the object in the new version's [`Objects::objects`]
+    pub new_index: usize,
+
+    pub old: &'a LevelObject,
+    pub new: &'a LevelObject,
+
+    /// The properties that changed. Never empty.
+    pub changes: Vec<PropertyChange>,
+}
+
+/// The differences between two versions of a level, see [`Objects::diff`]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LevelDiff<'a> {
+    /// Objects only present in the new version, together with their index in it
+    pub added: Vec<(usize, &'a LevelObject)>,
+
+    /// Objects only present in the old version, together with their index in it
+    pub removed: Vec<(usize, &'a LevelObject)>,
+
+    /// Objects present in both versions, but with different properties
+    pub modified: Vec<ModifiedObject<'a>>,
+
+    /// Changes to the level header
+    pub header_changes: Vec<PropertyChange>,
+}
+
+impl LevelDiff<'_> {
+    /// Whether the two versions of the level are equal, up to the order of their objects
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty() && self.header_changes.is_empty()
+    }
+}
+
+/// Serializes the given value and splits the resulting string into key-value pairs
+///
+/// The layout the value was read from is ignored, so keys that were explicitly written with their
+/// default value do not show up.
+pub(crate) fn properties<'de, T: GJFormat<'de>>(value: &T) -> Result<BTreeMap<String, String>, LevelProcessError> {
+    let mut buffer = Vec::new();
+
+    value.dash_serialize(&mut IndexedSerializer::new(T::DELIMITER, &mut buffer, T::MAP_LIKE))?;
+
+    let serialized = String::from_utf8(buffer).map_err(|err| LevelProcessError::Deserialize(err.to_string()))?;
+    let mut split = serialized.split(T::DELIMITER);
+    let mut properties = BTreeMap::new();
+
+    while let (Some(key), Some(value)) = (split.next(), split.next()) {
+        properties.insert(key.to_string(), value.to_string());
+    }
+
+    Ok(properties)
+}
+
+fn compare(old: &BTreeMap<String, String>, new: &BTreeMap<String, String>) -> Vec<PropertyChange> {
+    let keys: BTreeSet<_> = old.keys().chain(new.keys()).collect();
+
+    keys.into_iter()
+        .filter(|&key| old.get(key) != new.get(key))
+        .map(|key| PropertyChange {
+            key: key.clone(),
+            old: old.get(key).cloned(),
+            new: new.get(key).cloned(),
+        })
+        .collect()
+}
+
+/// Computes the key under which an object is matched with objects of the other version during
+/// the given pass of [`Objects::diff`]
+fn match_key(properties: &BTreeMap<String, String>, pass: usize) -> Vec<(&str, &str)> {
+    let is_position = |key: &str| key == "2" || key == "3";
+
+    properties
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .filter(|&(key, _)| match pass {
+            // identical objects
+            0 => true,
+            // same object at the same position, but with different properties
+            1 => key == "1" || is_position(key),
+            // same object with the same properties, but moved
+            _ => !is_position(key),
+        })
+        .collect()
+}
+
+impl Objects {
+    /// Computes the differences between this level and a newer version of it
+    ///
+    /// Objects are matched by their properties, not their index, so reordering objects does not
+    /// show up as a difference. Matching happens in three passes:
+    /// 1. Objects that are completely identical in both versions are considered unchanged.
+    /// 2. Objects with the same id at the same position are considered modified.
+    /// 3. Objects that differ only in their position are considered modified (moved).
+    ///
+    /// All remaining objects are reported as added or removed.
+    pub fn diff<'a>(&'a self, new: &'a Objects) -> Result<LevelDiff<'a>, LevelProcessError> {
+        let old_properties = self.objects.iter().map(properties).collect::<Result<Vec<_>, _>>()?;
+        let new_properties = new.objects.iter().map(properties).collect::<Result<Vec<_>, _>>()?;
+
+        let mut old_unmatched: Vec<bool> = vec![true; self.objects.len()];
+        let mut new_unmatched: Vec<bool> = vec![true; new.objects.len()];
+        let mut diff = LevelDiff::default();
+
+        for pass in 0..3 {
+            let mut candidates: HashMap<Vec<(&str, &str)>, Vec<usize>> = HashMap::new();
+
+            // reversed, so that popping from the candidate lists yields the earliest objects first
+            for (index, properties) in old_properties.iter().enumerate().rev() {
+                if old_unmatched[index] {
+                    candidates.entry(match_key(properties, pass)).or_default().push(index);
+                }
+            }
+
+            for (new_index, properties) in new_properties.iter().enumerate() {
+                if !new_unmatched[new_index] {
+                    continue;
+                }
+
+                let old_index = match candidates.get_mut(&match_key(properties, pass)).and_then(Vec::pop) {
+                    Some(old_index) => old_index,
+                    None => continue,
+                };
+
+                old_unmatched[old_index] = false;
+                new_unmatched[new_index] = false;
+
+                if pass > 0 {
+                    diff.modified.push(ModifiedObject {
+                        old_index,
+                        new_index,
+                        old: &self.objects[old_index],
+                        new: &new.objects[new_index],
+                        changes: compare(&old_properties[old_index], properties),
+                    })
+                }
+            }
+        }
+
+        diff.removed = old_unmatched
+            .iter()
+            .enumerate()
+            .filter(|(_, &unmatched)| unmatched)
+            .map(|(index, _)| (index, &self.objects[index]))
+            .collect();
+        diff.added = new_unmatched
+            .iter()
+            .enumerate()
+            .filter(|(_, &unmatched)| unmatched)
+            .map(|(index, _)| (index, &new.objects[index]))
+            .collect();
+        diff.modified.sort_by_key(|modified| modified.new_index);
+        diff.header_changes = compare(&properties(&self.meta)?, &properties(&new.meta)?);
+
+        Ok(diff)
+    }
+}
@@ -0,0 +1,144 @@
+//! Module containing types describing the state of the player at different points in a level
+
+use crate::model::level::{
+    object::{game_mode::GameMode, start_pos::StartPos, LevelObject, ObjectData},
+    Objects,
+};
+use serde::{Deserialize, Serialize};
+
+/// The state of the player as determined by the portals they passed through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct GameplayState {
+    pub game_mode: GameMode,
+
+    /// Whether the player is in mini mode
+    pub mini: bool,
+
+    /// Whether the player's gravity is pointing upwards
+    pub gravity_inverted: bool,
+
+    /// Whether there are two players
+    pub dual: bool,
+
+    /// Whether the screen is mirrored, e.g. the level scrolls from right to left
+    pub mirrored: bool,
+}
+
+impl GameplayState {
+    /// Updates this state as if the player passed through the object with the given data. Does
+    /// nothing if the object is not a portal affecting the player's state.
+    pub fn apply(&mut self, data: &ObjectData) {
+        match *data {
+            ObjectData::GameModePortal { game_mode, .. } => self.game_mode = game_mode,
+            ObjectData::SizePortal { mini } => self.mini = mini,
+            ObjectData::GravityPortal { gravity } => self.gravity_inverted = gravity.apply(self.gravity_inverted),
+            ObjectData::MirrorPortal { mirrored } => self.mirrored = mirrored,
+            ObjectData::DualPortal { dual } => self.dual = dual,
+            _ => {},
+        }
+    }
+}
+
+/// A part of a level throughout which the player's [`GameplayState`] does not change
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GameplaySection {
+    /// The x position at which this section starts. The section extends up to the start of the
+    /// next section.
+    pub start_x: f32,
+
+    pub state: GameplayState,
+}
+
+impl Objects {
+    /// Returns the state the player starts the level in, as described by the level's metadata
+    pub fn starting_state(&self) -> GameplayState {
+        GameplayState {
+            game_mode: self.meta.starting_game_mode,
+            mini: self.meta.starting_mini,
+            gravity_inverted: self.meta.start_gravity_inverted,
+            dual: self.meta.dual_start,
+            mirrored: false,
+        }
+    }
+
+    /// Returns the state of the player at the given x position, assuming they passed through every
+    /// portal placed at or before that position
+    pub fn gameplay_state_at(&self, x: f32) -> GameplayState {
+        let mut state = self.starting_state();
+
+        for object in self.portals_by_x().into_iter().take_while(|object| object.0 <= x) {
+            state.apply(object.1);
+        }
+
+        state
+    }
+
+    /// Splits the level into sections throughout which the player's state does not change
+    ///
+    /// The first section always starts at `0.0` with the [starting state](Objects::starting_state).
+    /// Portals that do not change the player's state (e.g. a cube portal while the player already
+    /// is a cube) do not start a new section.
+    pub fn gameplay_sections(&self) -> Vec<GameplaySection> {
+        let mut sections = vec![GameplaySection {
+            start_x: 0.0,
+            state: self.starting_state(),
+        }];
+
+        for (x, data) in self.portals_by_x() {
+            let last = sections[sections.len() - 1];
+            let mut state = last.state;
+
+            state.apply(data);
+
+            if state == last.state {
+                continue;
+            }
+
+            if x <= last.start_x {
+                sections.last_mut().unwrap().state = state;
+            } else {
+                sections.push(GameplaySection { start_x: x, state })
+            }
+        }
+
+        sections
+    }
+
+    /// Returns all start positions in this level, together with the objects they are attached
+    /// to, sorted by their x position
+    pub fn start_positions(&self) -> Vec<(&LevelObject, &StartPos)> {
+        let mut start_positions: Vec<_> = self
+            .objects
+            .iter()
+            .filter_map(|object| match &object.metadata {
+                ObjectData::StartPos(start_pos) => Some((object, start_pos)),
+                _ => None,
+            })
+            .collect();
+
+        start_positions.sort_by(|(object1, _), (object2, _)| object1.x.total_cmp(&object2.x));
+        start_positions
+    }
+
+    fn portals_by_x(&self) -> Vec<(f32, &ObjectData)> {
+        let mut portals: Vec<_> = self
+            .objects
+            .iter()
+            .filter(|object| {
+                matches!(
+                    object.metadata,
+                    ObjectData::GameModePortal { .. }
+                        | ObjectData::SizePortal { .. }
+                        | ObjectData::GravityPortal { .. }
+                        | ObjectData::MirrorPortal { .. }
+                        | ObjectData::DualPortal { .. }
+                )
+            })
+            .map(|object| (object.x, &object.metadata))
+            .collect();
+
+        // stable sort, so that portals at the same x position are applied in level order
+        portals.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
+        portals
+    }
+}
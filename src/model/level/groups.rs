@@ -0,0 +1,273 @@
+//! Module for indexing the objects of a level by group, and for renumbering the group ids a level
+//! uses
+//!
+//! Group id `0` is treated as "no group" throughout: it never appears in a [`GroupIndex`], is never
+//! remapped and is never returned as a free id.
+//!
+//! Triggers not modelled by [`ObjectData`] (such as stop or animate triggers) keep their target
+//! group (index `51`) and secondary group (index `71`) in their unknown properties. These are
+//! treated as group references as well, so that renumbering groups does not break such triggers.
+
+use crate::model::level::{
+    object::{
+        trigger::{PulseMode, PulseTarget},
+        LevelObject, ObjectCategory, ObjectData,
+    },
+    Objects, UnknownProperties,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// The highest group id the game allows
+pub const MAX_GROUP_ID: u16 = 9999;
+
+/// The highest id of a color channel that can be freely used by objects. Channels above it are used
+/// by the game for special purposes.
+pub const MAX_COLOR_ID: u16 = 999;
+
+/// Lists the group ids a trigger references, either as shared references or as mutable ones
+/// (`trigger_groups!(data, mut)`)
+macro_rules! trigger_groups {
+    ($data: expr $(, $mutability: ident)?) => {
+        match $data {
+            // The target position group is ignored by the game unless `use_target` is set
+            ObjectData::MoveTrigger(trigger) if trigger.use_target => {
+                vec![&$($mutability)? trigger.target_group, &$($mutability)? trigger.target_position_group]
+            },
+            ObjectData::MoveTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::RotateTrigger(trigger) => vec![&$($mutability)? trigger.target_group, &$($mutability)? trigger.center_group],
+            ObjectData::PulseTrigger(trigger) => match &$($mutability)? trigger.target {
+                PulseTarget::Group(group) => vec![group],
+                PulseTarget::ColorChannel(_) => vec![],
+            },
+            ObjectData::AlphaTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::FollowTrigger(trigger) => vec![&$($mutability)? trigger.target_group, &$($mutability)? trigger.follow_group],
+            ObjectData::SpawnTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::ToggleTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::CountTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::InstantCountTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::CollisionTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::TouchTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::RandomTrigger(trigger) => vec![&$($mutability)? trigger.group_1, &$($mutability)? trigger.group_2],
+            ObjectData::SequenceTrigger(trigger) => (&$($mutability)? trigger.steps)
+                .into_iter()
+                .map(|step| &$($mutability)? step.group)
+                .collect(),
+            ObjectData::ItemCompareTrigger(trigger) => vec![&$($mutability)? trigger.true_group, &$($mutability)? trigger.false_group],
+            ObjectData::CameraStaticTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::CameraEdgeTrigger(trigger) => vec![&$($mutability)? trigger.target_group],
+            ObjectData::AreaTrigger(trigger) => vec![&$($mutability)? trigger.target_group, &$($mutability)? trigger.center_group],
+            _ => vec![],
+        }
+    };
+}
+
+/// The indices of the unknown properties holding group references, for triggers not modelled by
+/// [`ObjectData`]
+const UNTYPED_TRIGGER_GROUP_KEYS: [&str; 2] = ["51", "71"];
+
+/// The indices of the unknown properties of the given object that hold group references
+fn untyped_group_keys(object: &LevelObject) -> &'static [&'static str] {
+    match object.metadata {
+        ObjectData::Unknown if object.category() == ObjectCategory::Trigger => &UNTYPED_TRIGGER_GROUP_KEYS,
+        _ => &[],
+    }
+}
+
+/// Calls `f` with the id stored at the given index of the unknown properties, if it is present and
+/// numeric, and writes back the id if `f` changed it
+pub(crate) fn visit_property(properties: &mut UnknownProperties, key: &str, f: &mut dyn FnMut(&mut u16)) {
+    if let Some(old) = properties.get(key).and_then(|value| value.parse().ok()) {
+        let mut id = old;
+
+        f(&mut id);
+
+        if id != old {
+            properties.set(key, id.to_string())
+        }
+    }
+}
+
+/// Calls `f` for every group the given object references without being part of it, see
+/// [`LevelObject::referenced_groups`]. Group `0` is skipped.
+pub(crate) fn visit_referenced_groups(object: &mut LevelObject, f: &mut dyn FnMut(&mut u16)) {
+    let mut visit = |group: &mut u16| {
+        if *group != 0 {
+            f(group)
+        }
+    };
+
+    for key in untyped_group_keys(object) {
+        visit_property(&mut object.unknown_properties, key, &mut visit)
+    }
+
+    for group in trigger_groups!(&mut object.metadata, mut) {
+        visit(group)
+    }
+}
+
+impl LevelObject {
+    /// The ids of the groups this object references without being part of them, e.g. the target
+    /// and center groups of a trigger. Does not contain group `0`.
+    pub fn referenced_groups(&self) -> Vec<u16> {
+        let mut groups: Vec<u16> = trigger_groups!(&self.metadata).into_iter().copied().collect();
+
+        groups.extend(
+            untyped_group_keys(self)
+                .iter()
+                .filter_map(|key| self.unknown_properties.get(key)?.parse::<u16>().ok()),
+        );
+
+        groups.retain(|&group| group != 0);
+        groups
+    }
+
+    /// The ids of the color channels this object uses or references, e.g. its own colors or the
+    /// target channel of a color trigger. Does not contain channel `0`.
+    pub fn referenced_colors(&self) -> Vec<u16> {
+        let mut colors = vec![self.main_color, self.detail_color];
+
+        match &self.metadata {
+            ObjectData::ColorTrigger(trigger) => colors.extend([trigger.target_channel, trigger.copied_channel]),
+            ObjectData::PulseTrigger(trigger) => {
+                if let PulseTarget::ColorChannel(channel) = trigger.target {
+                    colors.push(channel)
+                }
+                if let PulseMode::Hsv { copied_channel, .. } = trigger.mode {
+                    colors.push(copied_channel)
+                }
+            },
+            _ => (),
+        }
+
+        colors.retain(|&color| color != 0);
+        colors
+    }
+
+    /// Replaces every group id in this object's group list and in the groups it references that is
+    /// a key of `mapping` with the associated value
+    ///
+    /// Groups mapped to `0` are removed from the object's group list. Duplicates arising in the
+    /// group list are removed as well.
+    pub fn remap_groups(&mut self, mapping: &HashMap<u16, u16>) {
+        let mut remap = |group: &mut u16| {
+            if let Some(&new) = mapping.get(group) {
+                *group = new
+            }
+        };
+
+        visit_referenced_groups(self, &mut remap);
+
+        let mut seen = BTreeSet::new();
+
+        self.groups.iter_mut().for_each(&mut remap);
+        self.groups.retain(|&group| group != 0 && seen.insert(group));
+    }
+}
+
+/// Index from group id to the objects in that group and the objects referencing it, see
+/// [`Objects::group_index`]
+#[derive(Debug, Clone)]
+pub struct GroupIndex<'a> {
+    objects: &'a [LevelObject],
+
+    /// Maps each group to the indices of the objects that are part of it
+    members: BTreeMap<u16, Vec<usize>>,
+
+    /// Maps each group to the indices of the objects referencing it
+    referrers: BTreeMap<u16, Vec<usize>>,
+}
+
+impl<'a> GroupIndex<'a> {
+    /// All groups that objects are part of or that are referenced by some object, in ascending
+    /// order
+    pub fn groups(&self) -> BTreeSet<u16> {
+        self.members.keys().chain(self.referrers.keys()).copied().collect()
+    }
+
+    /// The indices in [`Objects::objects`] of all objects that are part of the given group, in
+    /// ascending order
+    pub fn members(&self, group: u16) -> &[usize] {
+        self.members.get(&group).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// The indices in [`Objects::objects`] of all objects referencing the given group (see
+    /// [`LevelObject::referenced_groups`]), in ascending order
+    pub fn referrers(&self, group: u16) -> &[usize] {
+        self.referrers.get(&group).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// All objects that are part of the given group
+    pub fn objects(&self, group: u16) -> impl Iterator<Item = &'a LevelObject> + '_ {
+        self.members(group).iter().map(move |&index| &self.objects[index])
+    }
+}
+
+impl Objects {
+    /// Builds an index from group id to the objects in that group and the objects referencing it
+    pub fn group_index(&self) -> GroupIndex<'_> {
+        let mut index = GroupIndex {
+            objects: &self.objects,
+            members: BTreeMap::new(),
+            referrers: BTreeMap::new(),
+        };
+
+        for (object_index, object) in self.objects.iter().enumerate() {
+            for &group in object.groups.iter().filter(|&&group| group != 0) {
+                index.members.entry(group).or_default().push(object_index);
+            }
+
+            let mut referenced = object.referenced_groups();
+            referenced.sort_unstable();
+            referenced.dedup();
+
+            for group in referenced {
+                index.referrers.entry(group).or_default().push(object_index);
+            }
+        }
+
+        index
+    }
+
+    /// Replaces every group id used in this level that is a key of `mapping` with the associated
+    /// value, in both the objects' group lists and the groups referenced by triggers, see
+    /// [`LevelObject::remap_groups`]
+    pub fn remap_groups(&mut self, mapping: &HashMap<u16, u16>) {
+        for object in &mut self.objects {
+            object.remap_groups(mapping)
+        }
+    }
+
+    /// Renumbers the groups used in this level to `1`, `2`, ..., preserving their relative order
+    ///
+    /// Returns the mapping that was applied, from old to new group id.
+    pub fn compact_groups(&mut self) -> HashMap<u16, u16> {
+        let mapping: HashMap<u16, u16> = self.group_index().groups().into_iter().zip(1..).collect();
+
+        self.remap_groups(&mapping);
+        mapping
+    }
+
+    /// Returns up to `count` group ids, in ascending order, that are neither used nor referenced by
+    /// any object in this level
+    ///
+    /// Fewer ids are returned if the level runs out of group ids below [`MAX_GROUP_ID`].
+    pub fn free_groups(&self, count: usize) -> Vec<u16> {
+        let used = self.group_index().groups();
+
+        (1..=MAX_GROUP_ID).filter(|group| !used.contains(group)).take(count).collect()
+    }
+
+    /// Returns up to `count` color channel ids, in ascending order, that are neither used by any
+    /// object nor defined in the level's metadata
+    ///
+    /// Only channels up to [`MAX_COLOR_ID`] are considered, so fewer ids may be returned.
+    pub fn free_colors(&self, count: usize) -> Vec<u16> {
+        let mut used: BTreeSet<u16> = self.objects.iter().flat_map(LevelObject::referenced_colors).collect();
+
+        for channel in &self.meta.color_channels {
+            used.extend([channel.id, channel.copied_channel]);
+        }
+
+        (1..=MAX_COLOR_ID).filter(|color| !used.contains(color)).take(count).collect()
+    }
+}
@@ -0,0 +1,272 @@
+//! Module for computing the hitboxes of a level's objects in world space and querying them
+//! spatially
+//!
+//! The shape of an object's hitbox is taken from the object catalogue in
+//! [`ids`](crate::model::level::object::ids). Objects missing from it, or without a hitbox, are
+//! ignored.
+
+use crate::model::level::{
+    object::{
+        ids::{HitboxShape, HitboxType},
+        LevelObject,
+    },
+    stats::BoundingBox,
+    Objects,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The width of the columns [`HitboxIndex`] sorts hitboxes into
+const COLUMN_WIDTH: f32 = 150.0;
+
+/// The geometry of a hitbox in world space
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Geometry {
+    /// A convex polygon, given by its corners
+    Polygon(Vec<(f32, f32)>),
+
+    /// A circle. Non-uniform scaling is approximated by using the larger of the two scale factors.
+    Circle { x: f32, y: f32, radius: f32 },
+}
+
+impl Geometry {
+    /// The smallest axis-aligned rectangle containing this geometry
+    pub fn bounding_box(&self) -> BoundingBox {
+        match *self {
+            Geometry::Polygon(ref corners) => corners.iter().fold(
+                BoundingBox {
+                    min_x: f32::INFINITY,
+                    min_y: f32::INFINITY,
+                    max_x: f32::NEG_INFINITY,
+                    max_y: f32::NEG_INFINITY,
+                },
+                |bounds, &(x, y)| BoundingBox {
+                    min_x: bounds.min_x.min(x),
+                    min_y: bounds.min_y.min(y),
+                    max_x: bounds.max_x.max(x),
+                    max_y: bounds.max_y.max(y),
+                },
+            ),
+            Geometry::Circle { x, y, radius } => BoundingBox {
+                min_x: x - radius,
+                min_y: y - radius,
+                max_x: x + radius,
+                max_y: y + radius,
+            },
+        }
+    }
+
+    /// Whether this geometry overlaps the given axis-aligned rectangle. Touching edges do not count
+    /// as overlapping.
+    pub fn overlaps(&self, area: &BoundingBox) -> bool {
+        match *self {
+            Geometry::Circle { x, y, radius } => {
+                let dx = x - x.max(area.min_x).min(area.max_x);
+                let dy = y - y.max(area.min_y).min(area.max_y);
+
+                dx * dx + dy * dy < radius * radius
+            },
+            Geometry::Polygon(ref corners) => {
+                // Separating axis theorem: the shapes are disjoint iff there is an axis onto which
+                // their projections do not overlap. For a rectangle and a convex polygon, it suffices
+                // to check the coordinate axes and the normals of the polygon's edges.
+                let bounds = self.bounding_box();
+
+                if bounds.max_x <= area.min_x || bounds.min_x >= area.max_x || bounds.max_y <= area.min_y || bounds.min_y >= area.max_y {
+                    return false;
+                }
+
+                // The polygon lies within its bounding box, so only the part of the area inside of
+                // it matters. This also keeps the projections below finite for unbounded areas.
+                let area = BoundingBox {
+                    min_x: area.min_x.max(bounds.min_x),
+                    min_y: area.min_y.max(bounds.min_y),
+                    max_x: area.max_x.min(bounds.max_x),
+                    max_y: area.max_y.min(bounds.max_y),
+                };
+
+                let area_corners = [
+                    (area.min_x, area.min_y),
+                    (area.max_x, area.min_y),
+                    (area.max_x, area.max_y),
+                    (area.min_x, area.max_y),
+                ];
+
+                (0..corners.len()).all(|index| {
+                    let (x1, y1) = corners[index];
+                    let (x2, y2) = corners[(index + 1) % corners.len()];
+                    let axis = (y1 - y2, x2 - x1);
+
+                    let (min, max) = project(corners.iter().copied(), axis);
+                    let (area_min, area_max) = project(area_corners.iter().copied(), axis);
+
+                    max > area_min && area_max > min
+                })
+            },
+        }
+    }
+}
+
+fn project(points: impl Iterator<Item = (f32, f32)>, (axis_x, axis_y): (f32, f32)) -> (f32, f32) {
+    points
+        .map(|(x, y)| x * axis_x + y * axis_y)
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+/// The hitbox of a single object in world space
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hitbox<'a> {
+    /// The index of the object in [`Objects::objects`]
+    pub index: usize,
+    pub object: &'a LevelObject,
+    pub kind: HitboxType,
+    pub geometry: Geometry,
+}
+
+impl LevelObject {
+    /// Computes the kind and world space geometry of this object's hitbox, applying the object's
+    /// position, rotation, flip and scale
+    ///
+    /// Returns [`None`] if the object does not have a hitbox, or is not in the object catalogue.
+    pub fn hitbox(&self) -> Option<(HitboxType, Geometry)> {
+        let info = self.info()?;
+        let shape = info.shape?;
+
+        if info.hitbox == HitboxType::None {
+            return None;
+        }
+
+        let scale_x = self.scale * self.scale_x * if self.flipped_x { -1.0 } else { 1.0 };
+        let scale_y = self.scale * self.scale_y * if self.flipped_y { -1.0 } else { 1.0 };
+
+        // Rotations are clockwise in game
+        let (sin, cos) = (-self.rotation.to_radians()).sin_cos();
+        let (offset_x, offset_y) = info.hitbox_offset;
+
+        let to_world = |x: f32, y: f32| {
+            let (x, y) = ((x + offset_x) * scale_x, (y + offset_y) * scale_y);
+
+            (self.x + x * cos - y * sin, self.y + x * sin + y * cos)
+        };
+
+        let geometry = match shape {
+            HitboxShape::Rectangle { width, height } => {
+                let (half_width, half_height) = (width / 2.0, height / 2.0);
+
+                Geometry::Polygon(vec![
+                    to_world(-half_width, -half_height),
+                    to_world(half_width, -half_height),
+                    to_world(half_width, half_height),
+                    to_world(-half_width, half_height),
+                ])
+            },
+            HitboxShape::Slope { width, height } => {
+                let (half_width, half_height) = (width / 2.0, height / 2.0);
+
+                Geometry::Polygon(vec![
+                    to_world(-half_width, -half_height),
+                    to_world(half_width, -half_height),
+                    to_world(half_width, half_height),
+                ])
+            },
+            HitboxShape::Circle { radius } => {
+                let (x, y) = to_world(0.0, 0.0);
+
+                Geometry::Circle {
+                    x,
+                    y,
+                    radius: radius * scale_x.abs().max(scale_y.abs()),
+                }
+            },
+        };
+
+        Some((info.hitbox, geometry))
+    }
+}
+
+/// Spatial index over the hitboxes of a level's objects, see [`Objects::hitbox_index`]
+#[derive(Debug, Clone)]
+pub struct HitboxIndex<'a> {
+    hitboxes: Vec<Hitbox<'a>>,
+
+    /// Maps each column of width [`COLUMN_WIDTH`] to the indices (into `hitboxes`) of all hitboxes
+    /// whose bounding box intersects it
+    columns: BTreeMap<i32, Vec<usize>>,
+}
+
+fn column(x: f32) -> i32 {
+    (x / COLUMN_WIDTH).floor() as i32
+}
+
+impl<'a> HitboxIndex<'a> {
+    /// All hitboxes in the level, in the order of the objects they belong to
+    pub fn hitboxes(&self) -> &[Hitbox<'a>] {
+        &self.hitboxes
+    }
+
+    /// All hitboxes overlapping the given area, in the order of the objects they belong to
+    ///
+    /// Only the populated columns within the area are visited, so arbitrarily large areas are
+    /// fine.
+    pub fn query(&self, area: &BoundingBox) -> Vec<&Hitbox<'a>> {
+        let (first, last) = (column(area.min_x), column(area.max_x));
+
+        if first > last {
+            return Vec::new();
+        }
+
+        let mut candidates: Vec<usize> = self.columns.range(first..=last).flat_map(|(_, indices)| indices).copied().collect();
+
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        candidates
+            .into_iter()
+            .map(|index| &self.hitboxes[index])
+            .filter(|hitbox| hitbox.geometry.overlaps(area))
+            .collect()
+    }
+
+    /// All solid and hazard hitboxes overlapping the given area, that is, everything the player
+    /// can collide with
+    pub fn solids_and_hazards(&self, area: &BoundingBox) -> Vec<&Hitbox<'a>> {
+        let mut hitboxes = self.query(area);
+
+        hitboxes.retain(|hitbox| hitbox.kind == HitboxType::Solid || hitbox.kind == HitboxType::Hazard);
+        hitboxes
+    }
+}
+
+impl Objects {
+    /// Computes the world space hitboxes of all objects in this level and builds a spatial index
+    /// over them
+    pub fn hitbox_index(&self) -> HitboxIndex<'_> {
+        let mut index = HitboxIndex {
+            hitboxes: Vec::new(),
+            columns: BTreeMap::new(),
+        };
+
+        for (object_index, object) in self.objects.iter().enumerate() {
+            let (kind, geometry) = match object.hitbox() {
+                Some(hitbox) => hitbox,
+                None => continue,
+            };
+            let bounds = geometry.bounding_box();
+
+            for column in column(bounds.min_x)..=column(bounds.max_x) {
+                index.columns.entry(column).or_default().push(index.hitboxes.len());
+            }
+
+            index.hitboxes.push(Hitbox {
+                index: object_index,
+                object,
+                kind,
+                geometry,
+            });
+        }
+
+        index
+    }
+}
@@ -0,0 +1,276 @@
+//! Module for copying a [`Selection`] of objects from one level into another
+//!
+//! Besides positions, objects reference each other through four kinds of ids: groups, color
+//! channels, item counters and collision blocks. When merging, every such id used by the copied
+//! objects that is already in use in the target level is replaced by a free one, so that the
+//! copied objects keep working without interfering with the target's existing objects. Ids that
+//! do not collide are kept as they are.
+
+use crate::model::level::{
+    groups::{visit_property, visit_referenced_groups, MAX_COLOR_ID, MAX_GROUP_ID},
+    object::{
+        ids,
+        trigger::{ItemKind, ItemReference, PulseMode, PulseTarget},
+        LevelObject, ObjectData,
+    },
+    selection::Selection,
+    Objects,
+};
+use std::collections::{BTreeSet, HashMap};
+use thiserror::Error;
+
+/// The highest item and collision block id the game allows
+pub const MAX_ITEM_ID: u16 = 9999;
+
+/// The different kinds of ids that are reassigned when merging
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdKind {
+    Group,
+    Color,
+    Item,
+    CollisionBlock,
+}
+
+#[derive(Debug, Error)]
+pub enum MergeError {
+    /// The target level does not have enough free ids of the given kind left
+    #[error("Not enough free {0:?} ids left in target level")]
+    OutOfIds(IdKind),
+}
+
+/// The result of [`Objects::merge`]
+///
+/// Each mapping maps every id of its kind used by the copied objects to the id it was assigned in
+/// the target level. Ids that did not need to be reassigned map to themselves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Merged {
+    /// The copied objects in the target level
+    pub selection: Selection,
+
+    pub groups: HashMap<u16, u16>,
+    pub colors: HashMap<u16, u16>,
+
+    /// Mapping for the ids of item counters and timers
+    pub items: HashMap<u16, u16>,
+    pub collision_blocks: HashMap<u16, u16>,
+}
+
+/// Calls `f` for every group this object is part of or references, excluding group `0`
+fn visit_groups(object: &mut LevelObject, f: &mut dyn FnMut(&mut u16)) {
+    for group in object.groups.iter_mut().filter(|group| **group != 0) {
+        f(group)
+    }
+
+    visit_referenced_groups(object, f)
+}
+
+/// Calls `f` for every color channel this object uses, excluding channel `0`
+fn visit_colors(object: &mut LevelObject, f: &mut dyn FnMut(&mut u16)) {
+    let mut visit = |color: &mut u16| {
+        if *color != 0 {
+            f(color)
+        }
+    };
+
+    visit(&mut object.main_color);
+    visit(&mut object.detail_color);
+
+    match &mut object.metadata {
+        ObjectData::ColorTrigger(trigger) => {
+            visit(&mut trigger.target_channel);
+            visit(&mut trigger.copied_channel);
+        },
+        ObjectData::PulseTrigger(trigger) => {
+            if let PulseTarget::ColorChannel(channel) = &mut trigger.target {
+                visit(channel)
+            }
+            if let PulseMode::Hsv { copied_channel, .. } = &mut trigger.mode {
+                visit(copied_channel)
+            }
+        },
+        _ => (),
+    }
+}
+
+/// Calls `f` for every item counter or timer id this object uses, excluding id `0`
+fn visit_items(object: &mut LevelObject, f: &mut dyn FnMut(&mut u16)) {
+    let mut visit = |id: &mut u16| {
+        if *id != 0 {
+            f(id)
+        }
+    };
+    let mut visit_reference = |item: &mut ItemReference| {
+        // Points, the main time and attempts are global counters whose id is meaningless
+        if let ItemKind::Item | ItemKind::Timer = item.kind {
+            visit(&mut item.id)
+        }
+    };
+
+    match &mut object.metadata {
+        ObjectData::CountTrigger(trigger) => visit(&mut trigger.item_id),
+        ObjectData::InstantCountTrigger(trigger) => visit(&mut trigger.item_id),
+        ObjectData::PickupTrigger(trigger) => visit(&mut trigger.item_id),
+        ObjectData::ItemEditTrigger(trigger) => {
+            for item in [&mut trigger.item_1, &mut trigger.item_2, &mut trigger.target] {
+                visit_reference(item)
+            }
+        },
+        ObjectData::ItemCompareTrigger(trigger) => {
+            for item in [&mut trigger.item_1, &mut trigger.item_2] {
+                visit_reference(item)
+            }
+        },
+        // Item counters and collision blocks are not modelled by `ObjectData`, so their id (at index
+        // `80`) ends up in the unknown properties
+        _ if object.id == ids::ITEM_COUNTER => visit_property(&mut object.unknown_properties, "80", &mut visit),
+        _ => (),
+    }
+}
+
+/// Calls `f` for every collision block id this object uses, excluding id `0`
+fn visit_collision_blocks(object: &mut LevelObject, f: &mut dyn FnMut(&mut u16)) {
+    let mut visit = |id: &mut u16| {
+        if *id != 0 {
+            f(id)
+        }
+    };
+
+    match &mut object.metadata {
+        ObjectData::CollisionTrigger(trigger) => {
+            visit(&mut trigger.block_a);
+            visit(&mut trigger.block_b);
+        },
+        _ if object.id == ids::COLLISION_BLOCK => visit_property(&mut object.unknown_properties, "80", &mut visit),
+        _ => (),
+    }
+}
+
+/// Calls the given function for every id of some kind used by an object
+type Visitor = fn(&mut LevelObject, &mut dyn FnMut(&mut u16));
+
+fn collect(objects: &mut [LevelObject], visitor: Visitor) -> BTreeSet<u16> {
+    let mut ids = BTreeSet::new();
+
+    for object in objects {
+        visitor(object, &mut |id| {
+            ids.insert(*id);
+        });
+    }
+
+    ids
+}
+
+fn remap(objects: &mut [LevelObject], mapping: &HashMap<u16, u16>, visitor: Visitor) {
+    for object in objects {
+        visitor(object, &mut |id| {
+            if let Some(&new) = mapping.get(id) {
+                *id = new
+            }
+        })
+    }
+}
+
+/// Assigns every id in `needed` an id not in `used`, keeping ids that are not in `used` unchanged
+fn assign(kind: IdKind, needed: &BTreeSet<u16>, used: &BTreeSet<u16>, max: u16) -> Result<HashMap<u16, u16>, MergeError> {
+    let (kept, colliding): (Vec<u16>, Vec<u16>) = needed.iter().copied().partition(|id| !used.contains(id));
+    let mut free = (1..=max).filter(|id| !used.contains(id) && !needed.contains(id));
+    let mut mapping: HashMap<u16, u16> = kept.into_iter().map(|id| (id, id)).collect();
+
+    for id in colliding {
+        mapping.insert(id, free.next().ok_or(MergeError::OutOfIds(kind))?);
+    }
+
+    Ok(mapping)
+}
+
+impl Objects {
+    /// Copies the selected objects of `source` into this level, moving them by the given offset
+    ///
+    /// Group, color channel, item and collision block ids of the copied objects that are already
+    /// in use in this level are reassigned, see the [module level documentation](self). The
+    /// definitions of the color channels used by the copied objects are copied into this level's
+    /// metadata. The copied objects are appended after this level's existing objects.
+    pub fn merge(&mut self, source: &Objects, selection: &Selection, offset_x: f32, offset_y: f32) -> Result<Merged, MergeError> {
+        let mut copied: Vec<LevelObject> = selection.objects(source).cloned().collect();
+
+        // groups
+        let needed = collect(&mut copied, visit_groups);
+        let used = collect(&mut self.objects, visit_groups);
+        let groups = assign(IdKind::Group, &needed, &used, MAX_GROUP_ID)?;
+
+        // color channels. Channels copying the color of another channel need that channel as well.
+        let source_channels = &source.meta.color_channels;
+
+        let mut needed = collect(&mut copied, visit_colors);
+        needed.retain(|&color| color <= MAX_COLOR_ID);
+
+        loop {
+            let copies: Vec<u16> = source_channels
+                .iter()
+                .filter(|channel| needed.contains(&channel.id) && (1..=MAX_COLOR_ID).contains(&channel.copied_channel))
+                .map(|channel| channel.copied_channel)
+                .filter(|copied| !needed.contains(copied))
+                .collect();
+
+            if copies.is_empty() {
+                break;
+            }
+
+            needed.extend(copies);
+        }
+
+        let mut used = collect(&mut self.objects, visit_colors);
+        used.extend(
+            self.meta
+                .color_channels
+                .iter()
+                .flat_map(|channel| [channel.id, channel.copied_channel]),
+        );
+
+        let colors = assign(IdKind::Color, &needed, &used, MAX_COLOR_ID)?;
+
+        // item counters and collision blocks
+        let needed = collect(&mut copied, visit_items);
+        let used = collect(&mut self.objects, visit_items);
+        let items = assign(IdKind::Item, &needed, &used, MAX_ITEM_ID)?;
+
+        let needed = collect(&mut copied, visit_collision_blocks);
+        let used = collect(&mut self.objects, visit_collision_blocks);
+        let collision_blocks = assign(IdKind::CollisionBlock, &needed, &used, MAX_ITEM_ID)?;
+
+        for object in &mut copied {
+            object.x += offset_x;
+            object.y += offset_y;
+            object.remap_groups(&groups);
+        }
+
+        remap(&mut copied, &colors, visit_colors);
+        remap(&mut copied, &items, visit_items);
+        remap(&mut copied, &collision_blocks, visit_collision_blocks);
+
+        let copied_channels: Vec<_> = source_channels
+            .iter()
+            .cloned()
+            .filter_map(|mut channel| {
+                channel.id = *colors.get(&channel.id)?;
+                channel.copied_channel = colors.get(&channel.copied_channel).copied().unwrap_or(channel.copied_channel);
+
+                Some(channel)
+            })
+            .collect();
+
+        self.meta.color_channels.extend(copied_channels);
+
+        let start = self.objects.len();
+
+        self.objects.extend(copied);
+
+        Ok(Merged {
+            selection: Selection::new(start..self.objects.len()),
+            groups,
+            colors,
+            items,
+            collision_blocks,
+        })
+    }
+}
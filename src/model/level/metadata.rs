@@ -1,13 +1,38 @@
-use crate::{model::level::object::speed::Speed, GJFormat};
+use crate::{
+    model::level::{
+        color::{ColorChannel, ColorChannels},
+        object::{game_mode::GameMode, speed::Speed},
+        UnknownProperties,
+    },
+    serde::ProcessedRef,
+    Dash, DeError, GJFormat, IndexedDeserializer, IndexedSerializer, ProcessError, SerError, ThunkProcessor,
+};
 use dash_rs_derive::Dash;
 use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, io::Write};
 
-#[derive(Debug, PartialEq, Clone, Default, Copy, Serialize, Deserialize, Dash)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize, Dash)]
 pub struct LevelMetadata {
     #[dash(index = "kA4")]
-    #[dash(default = "one")]
+    #[dash(default)]
     pub starting_speed: Speed,
 
+    /// The game mode the player starts the level in
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA2`
+    #[dash(index = "kA2")]
+    #[dash(default)]
+    pub starting_game_mode: GameMode,
+
+    /// Whether the player starts the level in mini mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA3`
+    #[dash(index = "kA3")]
+    #[dash(default)]
+    pub starting_mini: bool,
+
     #[dash(index = "kA13")]
     #[dash(default)]
     pub song_offset: f64,
@@ -20,6 +45,14 @@ pub struct LevelMetadata {
     #[dash(default)]
     pub song_fade_out: bool,
 
+    /// The guidelines placed along the level's song
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA14`, see [`Guidelines`] for the format
+    #[dash(index = "kA14")]
+    #[dash(default)]
+    pub guidelines: Vec<Guideline>,
+
     #[dash(index = "kA8")]
     #[dash(default)]
     pub dual_start: bool,
@@ -31,31 +64,272 @@ pub struct LevelMetadata {
     #[dash(index = "kA11")]
     #[dash(default)]
     pub start_gravity_inverted: bool,
-    // ... other fields in the metadata section ...
+
+    /// Whether this level is a platformer level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA22`
+    #[dash(index = "kA22")]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "crate::util::is_default")]
+    pub platformer: bool,
+
+    /// The background texture used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA6`
+    #[dash(index = "kA6")]
+    #[dash(default)]
+    pub background_texture: BackgroundTexture,
+
+    /// The ground texture used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA7`
+    #[dash(index = "kA7")]
+    #[dash(default)]
+    pub ground_texture: GroundTexture,
+
+    /// The middleground texture used by this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA25`
+    #[dash(index = "kA25")]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "crate::util::is_default")]
+    pub middleground_texture: MiddlegroundTexture,
+
+    /// The style of the line drawn on top of the ground
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA17`
+    #[dash(index = "kA17")]
+    #[dash(default)]
+    pub ground_line: GroundLine,
+
+    /// The font used by text objects in this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA18`
+    #[dash(index = "kA18")]
+    #[dash(default)]
+    pub font: Font,
+
+    /// The page of the color channel selection the editor was last showing. Has no effect on
+    /// gameplay.
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kS39`
+    #[dash(index = "kS39")]
+    #[dash(default)]
+    pub color_page: u8,
+
+    /// The color channels of this level
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kS38`, see [`ColorChannels`] for the format
+    #[dash(index = "kS38")]
+    #[dash(default)]
+    #[dash(skip_serializing_if = "no_color_channels")]
+    pub color_channels: Vec<ColorChannel>,
+
+    /// All key-value pairs in the metadata section that dash-rs does not map to any of the above
+    /// fields, in the order they appeared in
+    #[dash(flatten)]
+    pub unknown_properties: UnknownProperties,
+}
+
+fn no_color_channels(channels: &ProcessedRef<ColorChannels>) -> bool {
+    channels.0.is_empty()
 }
 
 impl<'de> GJFormat<'de> for LevelMetadata {
     const DELIMITER: &'static str = ",";
     const MAP_LIKE: bool = true;
+
+    fn from_gj_str(input: &'de str) -> Result<Self, DeError<'de>> {
+        let mut metadata = Self::dash_deserialize(&mut IndexedDeserializer::new(input, Self::DELIMITER, Self::MAP_LIKE))?;
+
+        metadata.unknown_properties.record_layout(input, Self::DELIMITER);
+
+        Ok(metadata)
+    }
+
+    fn write_gj<W: Write>(&self, writer: W) -> Result<(), SerError> {
+        let mut buffer = Vec::new();
+
+        self.dash_serialize(&mut IndexedSerializer::new(Self::DELIMITER, &mut buffer, Self::MAP_LIKE))?;
+        self.unknown_properties.write_in_layout(&buffer, Self::DELIMITER, writer)
+    }
+}
+
+/// The background texture used by a level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum BackgroundTexture {
+    /// The level never changed its background. The game treats this the same as `Index(1)`, the
+    /// first background.
+    #[default]
+    Default,
+
+    /// One of the game's backgrounds, numbered from `1` to [`BackgroundTexture::MAX_INDEX`]
+    Index(u8),
+
+    /// An index the game does not have a background for
+    Unknown(u8),
+}
+
+/// The ground texture used by a level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GroundTexture {
+    /// The level never changed its ground. The game treats this the same as `Index(1)`, the first
+    /// ground.
+    #[default]
+    Default,
+
+    /// One of the game's grounds, numbered from `1` to [`GroundTexture::MAX_INDEX`]
+    Index(u8),
+
+    /// An index the game does not have a ground for
+    Unknown(u8),
+}
+
+/// The middleground texture used by a level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MiddlegroundTexture {
+    /// The level has no middleground
+    #[default]
+    None,
+
+    /// One of the game's middlegrounds, numbered from `1` to [`MiddlegroundTexture::MAX_INDEX`]
+    Index(u8),
+
+    /// An index the game does not have a middleground for
+    Unknown(u8),
+}
+
+/// The style of the line drawn on top of a level's ground
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GroundLine {
+    /// The level never changed its ground line. The game treats this the same as `Index(1)`, the
+    /// first line style.
+    #[default]
+    Default,
+
+    /// One of the game's line styles, numbered from `1` to [`GroundLine::MAX_INDEX`]
+    Index(u8),
+
+    /// An index the game does not have a line style for
+    Unknown(u8),
+}
+
+/// The font used by a level's text objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Font {
+    /// The game's default font (Pusab)
+    #[default]
+    Default,
+
+    /// One of the game's additional fonts, numbered from `1` to [`Font::MAX_INDEX`]
+    Index(u8),
+
+    /// An index the game does not have a font for
+    Unknown(u8),
+}
+
+/// Implements the conversions between the given enums and their index. Index `0` maps to the
+/// given variant, indices up to the given maximum to `Index`, and all others to `Unknown`.
+macro_rules! index_conversion {
+    ($($t:ident: $zero:ident, $max:literal;)*) => {
+        $(
+            impl $t {
+                /// The highest index known to the game, as of update 2.2
+                pub const MAX_INDEX: u8 = $max;
+            }
+
+            impl From<u8> for $t {
+                fn from(index: u8) -> Self {
+                    match index {
+                        0 => $t::$zero,
+                        1..=$max => $t::Index(index),
+                        unknown => $t::Unknown(unknown),
+                    }
+                }
+            }
+
+            impl From<$t> for u8 {
+                fn from(texture: $t) -> Self {
+                    match texture {
+                        $t::$zero => 0,
+                        $t::Index(index) | $t::Unknown(index) => index,
+                    }
+                }
+            }
+
+            crate::into_conversion!($t, u8);
+        )*
+    };
+}
+
+index_conversion! {
+    BackgroundTexture: Default, 59;
+    GroundTexture: Default, 22;
+    MiddlegroundTexture: None, 3;
+    GroundLine: Default, 3;
+    Font: Default, 59;
+}
+
+/// A guideline placed along a level's song in the editor
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Guideline {
+    /// The point in the song this guideline is placed at, in seconds
+    pub time: f32,
+
+    /// The color of this guideline
+    ///
+    /// The editor uses `0.8` for orange, `0.9` for yellow and `1.0` for green guidelines
+    pub color: f32,
+}
+
+/// [`ThunkProcessor`] for a level's list of [`Guideline`]s
+///
+/// ## GD Internals:
+/// Guidelines are stored as a `~`-separated list alternating between the time and the color of
+/// each guideline, e.g. `1.5~0.8~3~0.9~`.
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize, Clone, Copy)]
+pub struct Guidelines;
+
+impl ThunkProcessor for Guidelines {
+    type Error = ProcessError;
+    type Output<'a> = Vec<Guideline>;
+
+    fn from_unprocessed(unprocessed: Cow<'_, str>) -> Result<Self::Output<'_>, Self::Error> {
+        let mut split = unprocessed.split('~').filter(|value| !value.is_empty());
+        let mut guidelines = Vec::new();
+
+        while let Some(time) = split.next() {
+            let color = split.next().ok_or(ProcessError::IncorrectLength { expected: 2 })?;
+
+            guidelines.push(Guideline {
+                time: time.parse()?,
+                color: color.parse()?,
+            })
+        }
+
+        Ok(guidelines)
+    }
+
+    fn as_unprocessed<'b>(processed: &'b Self::Output<'_>) -> Result<Cow<'b, str>, Self::Error> {
+        Ok(Cow::Owned(
+            processed
+                .iter()
+                .map(|guideline| format!("{}~{}~", guideline.time, guideline.color))
+                .collect(),
+        ))
+    }
+
+    fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
+        output
+    }
 }
 
-fn one() -> u8 {
-    1
-}
-
-// starting_speed(index = kA4),
-// song_offset(index = kA13),
-// fade_in(index = kA15),
-// fade_out(index = kA16),
-// song guidelines: kA16
-// background texture index: kA6
-// ground texture index: kA7
-// ground line index: kA17
-// font: kA18
-// color page (???): kS39
-// starting game mode: kA2
-// starting size: kA3
-// dual_start(index = kA8),
-// level/start pos (???): kA9
-// two_player_controls(index = kA10),
-// start_gravity_inverted(index = kA11, optional),
+crate::processed_conversion!(Vec<Guideline>, Guidelines);
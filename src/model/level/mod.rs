@@ -5,36 +5,57 @@ use itoa::Buffer;
 use std::{
     borrow::Cow,
     fmt::{Display, Formatter},
-    io::Read,
+    io::{Read, Write},
 };
 use thiserror::Error;
 use variant_partial_eq::VariantPartialEq;
 
 use base64::{engine::general_purpose::URL_SAFE, Engine};
-use flate2::read::{GzDecoder, GzEncoder, ZlibDecoder};
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use serde::{
+    de::{MapAccess, Visitor},
+    ser::Error as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
 use crate::{
     model::{
         creator::Creator,
         level::{
+            builder::CompressionScheme,
             metadata::LevelMetadata,
-            object::{speed::Speed, LevelObject, ObjectData},
+            object::{LevelObject, ObjectData},
         },
         song::{MainSong, NewgroundsSong},
         GameVersion,
     },
-    serde::{Base64Decoder, ProcessError, Thunk, ThunkProcessor},
+    serde::{Base64Decoder, InternalProxy, ProcessError, Thunk, ThunkProcessor},
     util, Dash, GJFormat, SerError,
 };
-use flate2::Compression;
 
 // use flate2::read::GzDecoder;
 // use std::io::Read;
 
+pub mod builder;
+pub mod canonical;
+pub mod coins;
+pub mod color;
+pub mod diff;
+pub mod gameplay;
+pub mod groups;
+pub mod hitbox;
 mod internal;
+pub mod merge;
 pub mod metadata;
 pub mod object;
+pub mod selection;
+pub mod simulation;
+pub mod stats;
+pub mod stream;
+pub mod svg;
+pub mod timeline;
+pub mod transform;
+pub mod trigger_graph;
 
 /// Enum representing the possible level lengths known to dash-rs
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
@@ -786,6 +807,198 @@ pub struct Objects {
     pub objects: Vec<LevelObject>,
 }
 
+/// Key-value pairs from RobTop's level string format that dash-rs does not (yet) map to any field
+///
+/// These are retained in the order in which they appeared in the level string, so that processing
+/// and re-encoding level data does not lose any information.
+///
+/// When read via [`GJFormat::from_gj_str`], this additionally remembers the layout of the string the
+/// surrounding object was read from: the order of all its keys, and which known keys were written
+/// explicitly even though they held their default value (and would thus usually be omitted). Writing
+/// the object via [`GJFormat::write_gj`] reproduces this layout, so that unmodified objects are
+/// re-encoded to exactly the string they were read from. The layout is not part of the object's
+/// value: it is ignored when comparing and is not (de)serialized to self-describing formats.
+#[derive(Debug, Clone, Default)]
+pub struct UnknownProperties {
+    properties: Vec<(String, String)>,
+
+    /// The keys of the string the surrounding object was read from, in order, each followed by the
+    /// delimiter
+    layout: String,
+
+    /// Known keys that were explicitly written with their default value, together with their raw
+    /// value
+    explicit_defaults: Vec<(String, String)>,
+}
+
+impl PartialEq for UnknownProperties {
+    fn eq(&self, other: &Self) -> bool {
+        self.properties == other.properties
+    }
+}
+
+impl Eq for UnknownProperties {}
+
+impl UnknownProperties {
+    /// Returns the raw value stored for the given key, if any
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.properties.iter().find(|(k, _)| k == key).map(|(_, value)| value.as_str())
+    }
+
+    /// Sets the raw value of the given key. If the key is already present, its value is replaced
+    /// in-place, otherwise the key-value pair is appended.
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        let key = key.into();
+
+        match self.properties.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old_value)) => *old_value = value.into(),
+            None => self.properties.push((key, value.into())),
+        }
+    }
+
+    /// Removes the given key, returning its raw value if it was present
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let position = self.properties.iter().position(|(k, _)| k == key)?;
+
+        Some(self.properties.remove(position).1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.properties.iter().map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Remembers the layout of the given string, which the surrounding object was just read from
+    ///
+    /// Restores the raw values and original order of the properties, as these might have been
+    /// deserialized to some known field first, or been collected out of order.
+    pub(crate) fn record_layout(&mut self, input: &str, delimiter: &str) {
+        let mut split = input.split(delimiter);
+
+        while let (Some(key), Some(value)) = (split.next(), split.next()) {
+            self.layout.push_str(key);
+            self.layout.push_str(delimiter);
+
+            if let Some((_, old_value)) = self.properties.iter_mut().find(|(k, _)| k == key) {
+                *old_value = value.to_string();
+            }
+        }
+
+        let layout = &self.layout;
+
+        self.properties
+            .sort_by_key(|(key, _)| layout.split(delimiter).position(|k| k == key).unwrap_or(usize::MAX));
+    }
+
+    /// Remembers that the given known key was explicitly written with the given raw default value
+    pub(crate) fn record_explicit_default(&mut self, key: &str, value: &str) {
+        self.explicit_defaults.push((key.to_string(), value.to_string()))
+    }
+
+    /// Forgets the recorded layout, so that the surrounding object is written the same way as if it
+    /// had been constructed directly
+    pub(crate) fn clear_layout(&mut self) {
+        self.layout.clear();
+        self.explicit_defaults.clear();
+    }
+
+    /// Writes the given serialized representation of the surrounding object in the recorded
+    /// layout, see [`UnknownProperties::record_layout`]
+    ///
+    /// Keys that were not part of the original string are appended in the order in which they
+    /// were serialized.
+    pub(crate) fn write_in_layout<W: Write>(&self, serialized: &[u8], delimiter: &str, mut writer: W) -> Result<(), SerError> {
+        if self.layout.is_empty() {
+            return Ok(writer.write_all(serialized)?);
+        }
+
+        let serialized = std::str::from_utf8(serialized).map_err(SerError::custom)?;
+        let mut split = serialized.split(delimiter);
+        let mut pairs = Vec::new();
+
+        while let (Some(key), Some(value)) = (split.next(), split.next()) {
+            pairs.push(Some((key, value)));
+        }
+
+        let mut ordered = Vec::with_capacity(pairs.len() + self.explicit_defaults.len());
+
+        for key in self.layout.split_terminator(delimiter) {
+            if let Some(pair) = pairs.iter_mut().find(|pair| matches!(pair, Some((k, _)) if *k == key)) {
+                ordered.extend(pair.take())
+            } else if let Some((key, value)) = self.explicit_defaults.iter().find(|(k, _)| k == key) {
+                ordered.push((key.as_str(), value.as_str()))
+            }
+        }
+
+        ordered.extend(pairs.into_iter().flatten());
+
+        for (index, (key, value)) in ordered.into_iter().enumerate() {
+            if index != 0 {
+                writer.write_all(delimiter.as_bytes())?;
+            }
+
+            writer.write_all(key.as_bytes())?;
+            writer.write_all(delimiter.as_bytes())?;
+            writer.write_all(value.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+// These (de)serialize as maps, both in RobTop's format (where they are flattened into the
+// surrounding struct) and in any self-describing format.
+impl Serialize for UnknownProperties {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownProperties {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PropertiesVisitor;
+
+        impl<'de> Visitor<'de> for PropertiesVisitor {
+            type Value = UnknownProperties;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a map of level string properties")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut properties = Vec::new();
+
+                while let Some(entry) = map.next_entry()? {
+                    properties.push(entry);
+                }
+
+                Ok(UnknownProperties {
+                    properties,
+                    ..UnknownProperties::default()
+                })
+            }
+        }
+
+        deserializer.deserialize_map(PropertiesVisitor)
+    }
+}
+
+impl InternalProxy for UnknownProperties {
+    type DeserializeProxy = UnknownProperties;
+    type SerializeProxy<'a> = &'a UnknownProperties;
+
+    fn to_serialize_proxy(&self) -> Self::SerializeProxy<'_> {
+        self
+    }
+
+    fn from_deserialize_proxy(from: Self::DeserializeProxy) -> Self {
+        from
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum LevelProcessError {
     #[error("{0}")]
@@ -861,27 +1074,10 @@ impl ThunkProcessor for Objects {
     }
 
     fn as_unprocessed(processed: &Objects) -> Result<Cow<str>, LevelProcessError> {
-        let mut bytes = Vec::new();
-
-        processed.meta.write_gj(&mut bytes)?;
-
-        bytes.push(b';');
-
-        for object in &processed.objects {
-            object.write_gj(&mut bytes)?;
-            bytes.push(b';');
-        }
-
         // FIXME(game specific): Should we remember the compression scheme (zlib or gz) from above, or just
         // always re-compress using gz? Since the game dyncamially detects the compression method, we're
-        // compatible either way.
-
-        let mut encoder = GzEncoder::new(&bytes[..], Compression::new(9)); // TODO: idk what these values mean
-        let mut compressed = Vec::new();
-
-        encoder.read_to_end(&mut compressed)?;
-
-        Ok(Cow::Owned(URL_SAFE.encode(compressed)))
+        // compatible either way. Use a LevelBuilder to pick the scheme explicitly.
+        builder::encode(&processed.meta, &processed.objects, CompressionScheme::Gzip, 9).map(Cow::Owned)
     }
 
     fn downcast_output_lifetime<'b: 'c, 'c, 's>(output: &'s Self::Output<'b>) -> &'s Self::Output<'c> {
@@ -890,53 +1086,34 @@ impl ThunkProcessor for Objects {
 }
 
 impl Objects {
+    /// The time, in seconds, it takes the player to reach the end of this level. Shorthand for
+    /// `self.timeline().length_in_seconds()`.
     pub fn length_in_seconds(&self) -> f32 {
-        let mut portals = Vec::new();
-        let mut furthest_x = 0.0;
-
-        for object in &self.objects {
-            if let ObjectData::SpeedPortal { checked: true, speed } = object.metadata {
-                portals.push((object.x, speed))
-            }
-
-            furthest_x = f32::max(furthest_x, object.x);
-        }
-
-        portals.sort_by(|(x1, _), (x2, _)| x1.partial_cmp(x2).unwrap());
-
-        get_seconds_from_x_pos(furthest_x, self.meta.starting_speed, &portals)
-    }
-}
-
-fn get_seconds_from_x_pos(pos: f32, start_speed: Speed, portals: &[(f32, Speed)]) -> f32 {
-    let mut speed: f32 = start_speed.into();
-
-    if portals.is_empty() {
-        return pos / speed;
+        self.timeline().length_in_seconds()
     }
 
-    let mut last_obj_pos = 0.0;
-    let mut total_time = 0.0;
+    /// Decodes the content of all text objects in this level, sorted by x position
+    ///
+    /// Text objects at the same x position are sorted from top to bottom, so that lines of text
+    /// stacked on top of each other are returned in reading order.
+    pub fn texts(&self) -> Result<Vec<(&LevelObject, Cow<'_, str>)>, ProcessError> {
+        let mut texts = Vec::new();
 
-    for (x, portal_speed) in portals {
-        // distance between last portal and this one
-        let current_segment = x - last_obj_pos;
+        for object in &self.objects {
+            if let ObjectData::Text { ref text, .. } = object.metadata {
+                let decoded = match text.as_processed()? {
+                    Cow::Owned(decoded) => decoded,
+                    Cow::Borrowed(decoded) => Cow::Borrowed(decoded.as_ref()),
+                };
 
-        // break if we're past the position we want to calculate the position to
-        if pos <= current_segment {
-            break;
+                texts.push((object, decoded));
+            }
         }
 
-        // Calculate time spent in this segment and add to total time
-        total_time += current_segment / speed;
-
-        speed = (*portal_speed).into();
+        texts.sort_by(|(a, _), (b, _)| a.x.total_cmp(&b.x).then(b.y.total_cmp(&a.y)));
 
-        last_obj_pos = *x;
+        Ok(texts)
     }
-
-    // add the time spent between end and last portal to total time and return
-    (pos - last_obj_pos) / speed + total_time
 }
 
 #[cfg(test)]
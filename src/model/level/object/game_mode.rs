@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+/// Enum modelling the different game modes a player can be in during gameplay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum GameMode {
+    #[default]
+    Cube,
+    Ship,
+    Ball,
+    Ufo,
+    Wave,
+    Robot,
+    Spider,
+    Swing,
+    Unknown(u8),
+}
+
+impl From<u8> for GameMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => GameMode::Cube,
+            1 => GameMode::Ship,
+            2 => GameMode::Ball,
+            3 => GameMode::Ufo,
+            4 => GameMode::Wave,
+            5 => GameMode::Robot,
+            6 => GameMode::Spider,
+            7 => GameMode::Swing,
+            unknown => GameMode::Unknown(unknown),
+        }
+    }
+}
+
+impl From<GameMode> for u8 {
+    fn from(mode: GameMode) -> Self {
+        match mode {
+            GameMode::Cube => 0,
+            GameMode::Ship => 1,
+            GameMode::Ball => 2,
+            GameMode::Ufo => 3,
+            GameMode::Wave => 4,
+            GameMode::Robot => 5,
+            GameMode::Spider => 6,
+            GameMode::Swing => 7,
+            GameMode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+crate::into_conversion!(GameMode, u8);
@@ -1,5 +1,91 @@
-pub const SLOW_PORTAL: u16 = 200;
-pub const NORMAL_PORTAL: u16 = 201;
-pub const MEDIUM_PORTAL: u16 = 202;
-pub const FAST_PORTAL: u16 = 203;
-pub const VERY_FAST_PORTAL: u16 = 1334;
+//! Module containing the catalogue of known object ids
+//!
+//! The constants and the catalogue itself are generated at build time from `ids.yaml`, which lives
+//! next to this file. To teach dash-rs about a new object, add it there.
+//!
+//! ## Coverage
+//! The catalogue does not list every object id the game knows. It covers the objects dash-rs
+//! models explicitly: portals, triggers, pads, orbs and collectibles, the classic blocks and
+//! slabs, spikes and saw blades, as well as a few special decoration objects (such as text objects
+//! and collision blocks). Most decoration objects and most blocks added in update 2.0 and later are
+//! missing. For these, [`lookup`] returns [`None`], which means that
+//! [`LevelObject::category`](crate::model::level::object::LevelObject::category) reports them as
+//! decoration, and that they have no hitbox.
+
+use crate::model::level::object::ObjectCategory;
+use serde::{Deserialize, Serialize};
+
+/// The kind of hitbox an object has by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HitboxType {
+    /// The object has no hitbox and cannot be interacted with
+    None,
+
+    /// The player can stand on the object, but dies when running into its side
+    Solid,
+
+    /// The player dies when touching the object
+    Hazard,
+
+    /// The object does something when the player touches it, e.g. an orb, pad, portal or coin
+    Special,
+}
+
+/// The shape of an object's hitbox, in unscaled and unrotated units centered on the object
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum HitboxShape {
+    Rectangle {
+        width: f32,
+        height: f32,
+    },
+    Circle {
+        radius: f32,
+    },
+
+    /// A right triangle whose hypotenuse rises from the bottom left to the top right
+    Slope {
+        width: f32,
+        height: f32,
+    },
+}
+
+/// Information about an object id, as stored in the catalogue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectInfo {
+    pub id: u16,
+
+    /// A human readable name for the object. Not necessarily unique.
+    pub name: &'static str,
+
+    pub category: ObjectCategory,
+
+    /// The kind of hitbox the object has, unless changed through its properties
+    pub hitbox: HitboxType,
+
+    /// Whether the object counts toward the level's object limit
+    pub counts_toward_limit: bool,
+
+    /// The shape of the object's hitbox in unscaled, unrotated units, or [`None`] if the object
+    /// has no hitbox
+    pub shape: Option<HitboxShape>,
+
+    /// The offset of the hitbox's center from the object's position, in unscaled, unrotated units
+    pub hitbox_offset: (f32, f32),
+}
+
+include!(concat!(env!("OUT_DIR"), "/ids.rs"));
+
+/// Looks up the given object id in the catalogue, returning [`None`] if the id is not part of it
+///
+/// See the [module level documentation](self) for which objects are covered.
+pub fn lookup(id: u16) -> Option<&'static ObjectInfo> {
+    CATALOGUE
+        .binary_search_by_key(&id, |info| info.id)
+        .ok()
+        .map(|index| &CATALOGUE[index])
+}
+
+/// All objects in the catalogue, sorted by id
+pub fn catalogue() -> &'static [ObjectInfo] {
+    CATALOGUE
+}
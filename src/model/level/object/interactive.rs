@@ -0,0 +1,167 @@
+use crate::model::level::object::ids;
+use serde::{Deserialize, Serialize};
+
+/// Enum modelling the different jump pads, which act as soon as the player touches them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Pad {
+    /// Launches the player upwards
+    Yellow,
+
+    /// Launches the player upwards, but less high than a yellow pad
+    Pink,
+
+    /// Launches the player upwards, higher than a yellow pad
+    Red,
+
+    /// Flips the player's gravity
+    Blue,
+
+    /// Flips the player's gravity and teleports them to the next surface
+    Spider,
+}
+
+impl Pad {
+    /// Returns the pad with the given object id, or [`None`] if that id does not belong to a pad
+    pub fn from_object_id(id: u16) -> Option<Pad> {
+        Some(match id {
+            ids::YELLOW_PAD => Pad::Yellow,
+            ids::PINK_PAD => Pad::Pink,
+            ids::RED_PAD => Pad::Red,
+            ids::BLUE_PAD => Pad::Blue,
+            ids::SPIDER_PAD => Pad::Spider,
+            _ => return None,
+        })
+    }
+
+    pub fn object_id(self) -> u16 {
+        match self {
+            Pad::Yellow => ids::YELLOW_PAD,
+            Pad::Pink => ids::PINK_PAD,
+            Pad::Red => ids::RED_PAD,
+            Pad::Blue => ids::BLUE_PAD,
+            Pad::Spider => ids::SPIDER_PAD,
+        }
+    }
+}
+
+/// Enum modelling the different orbs (except dash orbs), which act when the player clicks while
+/// touching them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Orb {
+    /// Makes the player jump
+    Yellow,
+
+    /// Makes the player jump, but less high than a yellow orb
+    Pink,
+
+    /// Makes the player jump, higher than a yellow orb
+    Red,
+
+    /// Flips the player's gravity
+    Blue,
+
+    /// Flips the player's gravity and makes them jump
+    Green,
+
+    /// Launches the player downwards
+    Black,
+
+    /// Flips the player's gravity and teleports them to the next surface
+    Spider,
+
+    /// Toggles a group on or off, without affecting the player's movement
+    Toggle,
+
+    /// Teleports the player to the position of a target group
+    Teleport,
+}
+
+impl Orb {
+    /// Returns the orb with the given object id, or [`None`] if that id does not belong to an orb
+    /// modelled by this enum
+    pub fn from_object_id(id: u16) -> Option<Orb> {
+        Some(match id {
+            ids::YELLOW_ORB => Orb::Yellow,
+            ids::PINK_ORB => Orb::Pink,
+            ids::RED_ORB => Orb::Red,
+            ids::BLUE_ORB => Orb::Blue,
+            ids::GREEN_ORB => Orb::Green,
+            ids::BLACK_ORB => Orb::Black,
+            ids::SPIDER_ORB => Orb::Spider,
+            ids::TOGGLE_ORB => Orb::Toggle,
+            ids::TELEPORT_ORB => Orb::Teleport,
+            _ => return None,
+        })
+    }
+
+    pub fn object_id(self) -> u16 {
+        match self {
+            Orb::Yellow => ids::YELLOW_ORB,
+            Orb::Pink => ids::PINK_ORB,
+            Orb::Red => ids::RED_ORB,
+            Orb::Blue => ids::BLUE_ORB,
+            Orb::Green => ids::GREEN_ORB,
+            Orb::Black => ids::BLACK_ORB,
+            Orb::Spider => ids::SPIDER_ORB,
+            Orb::Toggle => ids::TOGGLE_ORB,
+            Orb::Teleport => ids::TELEPORT_ORB,
+        }
+    }
+}
+
+/// Enum modelling the dash orbs, which make the player dash in a straight line for as long as they
+/// hold the button after clicking while touching them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DashOrb {
+    Green,
+
+    /// Additionally flips the player's gravity
+    Pink,
+}
+
+impl DashOrb {
+    /// Returns the dash orb with the given object id, or [`None`] if that id does not belong to a
+    /// dash orb
+    pub fn from_object_id(id: u16) -> Option<DashOrb> {
+        Some(match id {
+            ids::GREEN_DASH_ORB => DashOrb::Green,
+            ids::PINK_DASH_ORB => DashOrb::Pink,
+            _ => return None,
+        })
+    }
+
+    pub fn object_id(self) -> u16 {
+        match self {
+            DashOrb::Green => ids::GREEN_DASH_ORB,
+            DashOrb::Pink => ids::PINK_DASH_ORB,
+        }
+    }
+}
+
+/// Enum modelling the collectible coins
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Coin {
+    /// A secret coin, as found in the official levels
+    Secret,
+
+    /// A user coin, which only count towards the player's stats once the level's coins are verified
+    User,
+}
+
+impl Coin {
+    /// Returns the coin with the given object id, or [`None`] if that id does not belong to a coin
+    pub fn from_object_id(id: u16) -> Option<Coin> {
+        Some(match id {
+            ids::SECRET_COIN => Coin::Secret,
+            ids::USER_COIN => Coin::User,
+            _ => return None,
+        })
+    }
+
+    pub fn object_id(self) -> u16 {
+        match self {
+            Coin::Secret => ids::SECRET_COIN,
+            Coin::User => ids::USER_COIN,
+        }
+    }
+}
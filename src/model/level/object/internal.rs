@@ -1,94 +1,1038 @@
 use crate::{
-    model::level::object::{ids, speed::Speed, LevelObject, ObjectData},
-    Dash, GJFormat,
+    model::level::{
+        color::HsvValue,
+        color::PlayerColor,
+        object::{
+            game_mode::GameMode,
+            ids,
+            interactive::{Coin, DashOrb, Orb, Pad},
+            portal::GravityChange,
+            speed::Speed,
+            start_pos::StartPos,
+            trigger::{
+                AlphaTrigger, AreaEffect, AreaTrigger, CameraEdgeTrigger, CameraModeTrigger, CameraOffsetTrigger, CameraStaticTrigger,
+                CameraZoomTrigger, CollisionTrigger, ColorTrigger, CountTrigger, FollowTrigger, GravityTrigger, InstantCountTrigger,
+                ItemCompareTrigger, ItemEditTrigger, ItemReference, MoveTrigger, PickupTrigger, PulseMode, PulseTarget, PulseTrigger,
+                RandomTrigger, RotateTrigger, SequenceStep, SequenceTrigger, SfxTrigger, Shader, ShaderTrigger, ShakeTrigger, SongTrigger,
+                SpawnTrigger, TimeWarpTrigger, ToggleTrigger, TouchTrigger, TriggerActivation,
+            },
+            LevelObject, ObjectData,
+        },
+        UnknownProperties,
+    },
+    serde::{Nested, Thunk},
+    Dash, DeError, GJFormat, IndexedDeserializer, IndexedSerializer, SerError,
 };
-use serde::{Deserialize, Serialize};
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::{borrow::Cow, fmt::Display, io::Write, str::FromStr};
+
+/// Declares [`InternalLevelObject`] from a list of `index => field: Type` mappings.
+///
+/// Apart from the id and the position, every field is optional. While converting into a
+/// [`LevelObject`], every field that is used gets [`Option::take`]n. Whatever remains afterwards
+/// (e.g. keys that are known to dash-rs, but are not applicable to the type of the object at hand)
+/// is moved into the object's unknown properties by the generated `retain_unconsumed` function, so
+/// that no data is lost.
+macro_rules! internal_level_object {
+    ($($(#[$attr:meta])* $index:literal => $field:ident: $t:ty,)*) => {
+        #[derive(Debug, Deserialize, Serialize, Clone, Default)]
+        pub struct InternalLevelObject {
+            #[serde(rename = "1")]
+            id: u16,
 
-#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
-pub struct InternalLevelObject {
-    #[serde(rename = "1")]
-    id: u16,
+            #[serde(rename = "2")]
+            x: f32,
 
-    #[serde(rename = "2")]
-    x: f32,
+            #[serde(rename = "3")]
+            y: f32,
 
-    #[serde(rename = "3")]
-    y: f32,
+            $(
+                $(#[$attr])*
+                #[serde(rename = $index, default, skip_serializing_if = "Option::is_none")]
+                $field: Option<$t>,
+            )*
 
-    #[serde(rename = "4", default)]
-    flipped_x: bool,
+            #[serde(flatten)]
+            unknown: UnknownProperties,
+        }
 
-    #[serde(rename = "5", default)]
-    flipped_y: bool,
+        impl InternalLevelObject {
+            /// Moves all fields that were not consumed during conversion into a [`LevelObject`]
+            /// to the unknown properties
+            fn retain_unconsumed(&mut self) -> Result<(), SerError> {
+                $(
+                    if let Some(value) = self.$field.take() {
+                        self.unknown.set($index, to_robtop_string(&value)?);
+                    }
+                )*
 
-    #[serde(rename = "6", default)]
-    rotation: f32,
+                Ok(())
+            }
+
+            /// Whether the given known key would be written when serializing this object
+            fn contains_key(&self, key: &str) -> bool {
+                match key {
+                    "1" | "2" | "3" => true,
+                    $($index => self.$field.is_some(),)*
+                    _ => false,
+                }
+            }
+        }
+    };
+}
 
-    // ... other common fields
+internal_level_object! {
+    "4" => flipped_x: bool,
+    "5" => flipped_y: bool,
+    "6" => rotation: f32,
+    "20" => editor_layer: u16,
+    "21" => main_color: u16,
+    "22" => detail_color: u16,
+    "24" => z_layer: i8,
+    "25" => z_order: i32,
+    "32" => scale: f32,
+    "34" => group_parent: bool,
+    "41" => main_hsv_enabled: bool,
+    "42" => detail_hsv_enabled: bool,
+    "43" => main_hsv: Nested<HsvValue>,
+    "44" => detail_hsv: Nested<HsvValue>,
+    "57" => groups: DotSeparated<u16>,
+    "61" => editor_layer_2: u16,
+    "64" => dont_fade: bool,
+    "67" => dont_enter: bool,
+    "96" => no_glow: bool,
+    "103" => high_detail: bool,
+    "108" => linked_group: u32,
+    "128" => scale_x: f32,
+    "129" => scale_y: f32,
+
+    // start position related fields
+    "kA2" => start_game_mode: u8,
+    "kA3" => start_mini: bool,
+    "kA4" => start_speed: u8,
+    "kA8" => start_dual: bool,
+    "kA11" => start_gravity_inverted: bool,
+    "kA21" => start_disabled: bool,
 
     // portal related fields
-    #[serde(rename = "13", default)]
-    checked: bool,
+    "13" => checked: bool,
+    "54" => teleport_offset: f32,
+
+    // trigger related fields
+    "7" => red: u8,
+    "8" => green: u8,
+    "9" => blue: u8,
+    "10" => duration: f32,
+    "11" => touch_triggered: bool,
+    "15" => player_color_1: bool,
+    "16" => player_color_2: bool,
+    "17" => blending: bool,
+    "23" => target_color: u16,
+    "28" => offset_x: f32,
+    "29" => offset_y: f32,
+    "30" => easing: u8,
+    "35" => opacity: f32,
+    "45" => fade_in: f32,
+    "46" => hold: f32,
+    "47" => fade_out: f32,
+    "48" => pulse_hsv_mode: bool,
+    "49" => copied_hsv: Nested<HsvValue>,
+    "50" => copied_color: u16,
+    "51" => target_group: u16,
+    "52" => pulse_group: bool,
+    "58" => lock_to_player_x: bool,
+    "59" => lock_to_player_y: bool,
+    "60" => copy_opacity: bool,
+    "62" => spawn_triggered: bool,
+    "65" => main_only: bool,
+    "66" => detail_only: bool,
+    "68" => degrees: f32,
+    "69" => full_rotations: i32,
+    "70" => lock_rotation: bool,
+    "71" => secondary_group: u16,
+    "72" => x_modifier: f32,
+    "73" => y_modifier: f32,
+    "75" => strength: f32,
+    "84" => interval: f32,
+    "85" => easing_rate: f32,
+    "86" => exclusive: bool,
+    "87" => multi_triggered: bool,
+    "100" => use_target: bool,
+
+    // logic trigger related fields
+    "56" => activate_group: bool,
+    "63" => delay: f32,
+    "77" => count: i32,
+    "80" => item_1: u16,
+    "81" => hold_mode: bool,
+    "82" => toggle_mode: u8,
+    "88" => count_comparison: u8,
+    "89" => dual_mode: bool,
+    "93" => trigger_on_exit: bool,
+    "95" => item_2: u16,
+    "104" => multi_activate: bool,
+    "435" => sequence: DotSeparated<u16>,
+    "436" => min_interval: f32,
+    "437" => sequence_mode: u8,
+    "476" => item_1_kind: u8,
+    "477" => item_2_kind: u8,
+    "478" => target_kind: u8,
+    "479" => modifier_1: f32,
+    "480" => item_operator_1: u8,
+    "481" => item_operator_2: u8,
+    "482" => item_operator_3: u8,
+    "483" => modifier_2: f32,
+
+    // text object related fields
+    "31" => text: String,
+    "488" => kerning: i32,
+
+    // pad and orb related fields
+    "99" => orb_multi_activate: bool,
+
+    // camera, audio and 2.2 trigger related fields
+    "110" => exit_static: bool,
+    "111" => free_mode: bool,
+    "120" => time_modifier: f32,
+    "148" => gravity: f32,
+    "164" => camera_edge: u8,
+    "371" => zoom: f32,
+    "392" => sound_id: u32,
+    "404" => sound_speed: f32,
+    "406" => volume: f32,
+}
+
+impl InternalLevelObject {
+    fn take_activation(&mut self) -> TriggerActivation {
+        TriggerActivation {
+            touch_triggered: self.touch_triggered.take().unwrap_or_default(),
+            spawn_triggered: self.spawn_triggered.take().unwrap_or_default(),
+            multi_triggered: self.multi_triggered.take().unwrap_or_default(),
+        }
+    }
+
+    fn set_activation(&mut self, activation: &TriggerActivation) {
+        self.touch_triggered = non_default(activation.touch_triggered, false);
+        self.spawn_triggered = non_default(activation.spawn_triggered, false);
+        self.multi_triggered = non_default(activation.multi_triggered, false);
+    }
+}
+
+/// Serializes a single value the way it would appear inside an object string
+fn to_robtop_string<T: Serialize>(value: &T) -> Result<String, SerError> {
+    let mut buffer = Vec::new();
+
+    value.serialize(&mut IndexedSerializer::new(",", &mut buffer, false))?;
+
+    String::from_utf8(buffer).map_err(SerError::custom)
+}
+
+/// Internal representation of a list of values that robtop stores as a single value, separating
+/// the individual entries with `.` (this is for example the case for an object's groups)
+#[derive(Debug, Clone, Default)]
+struct DotSeparated<T>(Vec<T>);
+
+impl<'de, T: FromStr> Deserialize<'de> for DotSeparated<T>
+where
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <&str>::deserialize(deserializer)?
+            .split('.')
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| entry.parse().map_err(D::Error::custom))
+            .collect::<Result<_, _>>()
+            .map(DotSeparated)
+    }
+}
+
+impl<T: Display> Serialize for DotSeparated<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let joined = self.0.iter().map(ToString::to_string).collect::<Vec<_>>().join(".");
+
+        serializer.serialize_str(&joined)
+    }
+}
+
+/// Returns [`None`] if `value` equals `default`, so that default values are omitted when
+/// serializing (which is what the game does, too).
+fn non_default<T: PartialEq>(value: T, default: T) -> Option<T> {
+    if value == default {
+        None
+    } else {
+        Some(value)
+    }
 }
 
 impl<'de> Dash<'de> for LevelObject {
     fn dash_deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let internal = InternalLevelObject::deserialize(deserializer)?;
+        let mut internal = InternalLevelObject::deserialize(deserializer)?;
 
         let metadata = match internal.id {
+            ids::START_POS => ObjectData::StartPos(StartPos {
+                speed: internal.start_speed.take().unwrap_or_default().into(),
+                game_mode: internal.start_game_mode.take().unwrap_or_default().into(),
+                mini: internal.start_mini.take().unwrap_or_default(),
+                dual: internal.start_dual.take().unwrap_or_default(),
+                gravity_inverted: internal.start_gravity_inverted.take().unwrap_or_default(),
+                disabled: internal.start_disabled.take().unwrap_or_default(),
+            }),
             ids::SLOW_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
+                checked: internal.checked.take().unwrap_or_default(),
                 speed: Speed::Slow,
             },
             ids::NORMAL_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
+                checked: internal.checked.take().unwrap_or_default(),
                 speed: Speed::Normal,
             },
+            ids::MEDIUM_PORTAL => ObjectData::SpeedPortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                speed: Speed::Medium,
+            },
             ids::FAST_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
+                checked: internal.checked.take().unwrap_or_default(),
                 speed: Speed::Fast,
             },
             ids::VERY_FAST_PORTAL => ObjectData::SpeedPortal {
-                checked: internal.checked,
+                checked: internal.checked.take().unwrap_or_default(),
                 speed: Speed::VeryFast,
             },
-            _ => ObjectData::Unknown,
+            ids::CUBE_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Cube,
+            },
+            ids::SHIP_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Ship,
+            },
+            ids::BALL_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Ball,
+            },
+            ids::UFO_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Ufo,
+            },
+            ids::WAVE_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Wave,
+            },
+            ids::ROBOT_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Robot,
+            },
+            ids::SPIDER_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Spider,
+            },
+            ids::SWING_PORTAL => ObjectData::GameModePortal {
+                checked: internal.checked.take().unwrap_or_default(),
+                game_mode: GameMode::Swing,
+            },
+            ids::NORMAL_SIZE_PORTAL => ObjectData::SizePortal { mini: false },
+            ids::MINI_PORTAL => ObjectData::SizePortal { mini: true },
+            ids::NORMAL_GRAVITY_PORTAL => ObjectData::GravityPortal {
+                gravity: GravityChange::Normal,
+            },
+            ids::INVERTED_GRAVITY_PORTAL => ObjectData::GravityPortal {
+                gravity: GravityChange::Inverted,
+            },
+            ids::TOGGLE_GRAVITY_PORTAL => ObjectData::GravityPortal {
+                gravity: GravityChange::Toggle,
+            },
+            ids::MIRROR_PORTAL => ObjectData::MirrorPortal { mirrored: true },
+            ids::UNMIRROR_PORTAL => ObjectData::MirrorPortal { mirrored: false },
+            ids::DUAL_PORTAL => ObjectData::DualPortal { dual: true },
+            ids::SINGLE_PORTAL => ObjectData::DualPortal { dual: false },
+            ids::TEXT => ObjectData::Text {
+                text: Thunk::Unprocessed(Cow::Owned(internal.text.take().unwrap_or_default())),
+                kerning: internal.kerning.take().unwrap_or_default(),
+            },
+            ids::TELEPORT_PORTAL => ObjectData::TeleportPortal {
+                offset_y: internal.teleport_offset.take().unwrap_or_default(),
+            },
+            ids::MOVE_TRIGGER => ObjectData::MoveTrigger(MoveTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                offset_x: internal.offset_x.take().unwrap_or_default(),
+                offset_y: internal.offset_y.take().unwrap_or_default(),
+                easing: internal.easing.take().unwrap_or_default().into(),
+                easing_rate: internal.easing_rate.take().unwrap_or_default(),
+                lock_to_player_x: internal.lock_to_player_x.take().unwrap_or_default(),
+                lock_to_player_y: internal.lock_to_player_y.take().unwrap_or_default(),
+                use_target: internal.use_target.take().unwrap_or_default(),
+                target_position_group: internal.secondary_group.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::ROTATE_TRIGGER => ObjectData::RotateTrigger(RotateTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                center_group: internal.secondary_group.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                degrees: internal.degrees.take().unwrap_or_default(),
+                full_rotations: internal.full_rotations.take().unwrap_or_default(),
+                lock_rotation: internal.lock_rotation.take().unwrap_or_default(),
+                easing: internal.easing.take().unwrap_or_default().into(),
+                easing_rate: internal.easing_rate.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::PULSE_TRIGGER => ObjectData::PulseTrigger(PulseTrigger {
+                target: match internal.pulse_group.take() {
+                    Some(true) => PulseTarget::Group(internal.target_group.take().unwrap_or_default()),
+                    _ => PulseTarget::ColorChannel(internal.target_group.take().unwrap_or_default()),
+                },
+                mode: match internal.pulse_hsv_mode.take() {
+                    Some(true) => PulseMode::Hsv {
+                        copied_channel: internal.copied_color.take().unwrap_or_default(),
+                        hsv: internal.copied_hsv.take().map(|hsv| hsv.0).unwrap_or_default(),
+                    },
+                    _ => PulseMode::Color {
+                        red: internal.red.take().unwrap_or_default(),
+                        green: internal.green.take().unwrap_or_default(),
+                        blue: internal.blue.take().unwrap_or_default(),
+                    },
+                },
+                fade_in: internal.fade_in.take().unwrap_or_default(),
+                hold: internal.hold.take().unwrap_or_default(),
+                fade_out: internal.fade_out.take().unwrap_or_default(),
+                exclusive: internal.exclusive.take().unwrap_or_default(),
+                main_only: internal.main_only.take().unwrap_or_default(),
+                detail_only: internal.detail_only.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::ALPHA_TRIGGER => ObjectData::AlphaTrigger(AlphaTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                opacity: internal.opacity.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::COLOR_TRIGGER => ObjectData::ColorTrigger(ColorTrigger {
+                target_channel: internal.target_color.take().unwrap_or(1),
+                red: internal.red.take().unwrap_or_default(),
+                green: internal.green.take().unwrap_or_default(),
+                blue: internal.blue.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                opacity: internal.opacity.take().unwrap_or_default(),
+                blending: internal.blending.take().unwrap_or_default(),
+                player_color: match (internal.player_color_1.take(), internal.player_color_2.take()) {
+                    (Some(true), _) => PlayerColor::Player1,
+                    (_, Some(true)) => PlayerColor::Player2,
+                    _ => PlayerColor::None,
+                },
+                copied_channel: internal.copied_color.take().unwrap_or_default(),
+                copied_hsv: internal.copied_hsv.take().map(|hsv| hsv.0).unwrap_or_default(),
+                copy_opacity: internal.copy_opacity.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::FOLLOW_TRIGGER => ObjectData::FollowTrigger(FollowTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                follow_group: internal.secondary_group.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                x_modifier: internal.x_modifier.take().unwrap_or_default(),
+                y_modifier: internal.y_modifier.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::SHAKE_TRIGGER => ObjectData::ShakeTrigger(ShakeTrigger {
+                duration: internal.duration.take().unwrap_or_default(),
+                strength: internal.strength.take().unwrap_or_default(),
+                interval: internal.interval.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::SPAWN_TRIGGER => ObjectData::SpawnTrigger(SpawnTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                delay: internal.delay.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::TOGGLE_TRIGGER => ObjectData::ToggleTrigger(ToggleTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                activate_group: internal.activate_group.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::COUNT_TRIGGER => ObjectData::CountTrigger(CountTrigger {
+                item_id: internal.item_1.take().unwrap_or_default(),
+                target_count: internal.count.take().unwrap_or_default(),
+                target_group: internal.target_group.take().unwrap_or_default(),
+                activate_group: internal.activate_group.take().unwrap_or_default(),
+                multi_activate: internal.multi_activate.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::INSTANT_COUNT_TRIGGER => ObjectData::InstantCountTrigger(InstantCountTrigger {
+                item_id: internal.item_1.take().unwrap_or_default(),
+                target_count: internal.count.take().unwrap_or_default(),
+                comparison: internal.count_comparison.take().unwrap_or_default().into(),
+                target_group: internal.target_group.take().unwrap_or_default(),
+                activate_group: internal.activate_group.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::PICKUP_TRIGGER => ObjectData::PickupTrigger(PickupTrigger {
+                item_id: internal.item_1.take().unwrap_or_default(),
+                count: internal.count.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::COLLISION_TRIGGER => ObjectData::CollisionTrigger(CollisionTrigger {
+                block_a: internal.item_1.take().unwrap_or_default(),
+                block_b: internal.item_2.take().unwrap_or_default(),
+                target_group: internal.target_group.take().unwrap_or_default(),
+                activate_group: internal.activate_group.take().unwrap_or_default(),
+                trigger_on_exit: internal.trigger_on_exit.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::TOUCH_TRIGGER => ObjectData::TouchTrigger(TouchTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                hold_mode: internal.hold_mode.take().unwrap_or_default(),
+                toggle_mode: internal.toggle_mode.take().unwrap_or_default().into(),
+                dual_mode: internal.dual_mode.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::RANDOM_TRIGGER => ObjectData::RandomTrigger(RandomTrigger {
+                group_1: internal.target_group.take().unwrap_or_default(),
+                group_2: internal.secondary_group.take().unwrap_or_default(),
+                chance: internal.duration.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::SEQUENCE_TRIGGER => ObjectData::SequenceTrigger(SequenceTrigger {
+                steps: internal
+                    .sequence
+                    .take()
+                    .map(|sequence| {
+                        sequence
+                            .0
+                            .chunks(2)
+                            .map(|step| SequenceStep {
+                                group: step[0],
+                                count: step.get(1).copied().unwrap_or_default(),
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                min_interval: internal.min_interval.take().unwrap_or_default(),
+                mode: internal.sequence_mode.take().unwrap_or_default().into(),
+                activation: internal.take_activation(),
+            }),
+            ids::ITEM_EDIT_TRIGGER => ObjectData::ItemEditTrigger(ItemEditTrigger {
+                item_1: ItemReference {
+                    id: internal.item_1.take().unwrap_or_default(),
+                    kind: internal.item_1_kind.take().unwrap_or_default().into(),
+                },
+                item_2: ItemReference {
+                    id: internal.item_2.take().unwrap_or_default(),
+                    kind: internal.item_2_kind.take().unwrap_or_default().into(),
+                },
+                target: ItemReference {
+                    id: internal.target_group.take().unwrap_or_default(),
+                    kind: internal.target_kind.take().unwrap_or_default().into(),
+                },
+                modifier: internal.modifier_1.take().unwrap_or_default(),
+                assign_operator: internal.item_operator_1.take().unwrap_or_default().into(),
+                operator_1: internal.item_operator_2.take().unwrap_or_default().into(),
+                operator_2: internal.item_operator_3.take().unwrap_or_default().into(),
+                activation: internal.take_activation(),
+            }),
+            ids::ITEM_COMPARE_TRIGGER => ObjectData::ItemCompareTrigger(ItemCompareTrigger {
+                item_1: ItemReference {
+                    id: internal.item_1.take().unwrap_or_default(),
+                    kind: internal.item_1_kind.take().unwrap_or_default().into(),
+                },
+                item_2: ItemReference {
+                    id: internal.item_2.take().unwrap_or_default(),
+                    kind: internal.item_2_kind.take().unwrap_or_default().into(),
+                },
+                modifier_1: internal.modifier_1.take().unwrap_or_default(),
+                modifier_2: internal.modifier_2.take().unwrap_or_default(),
+                operator_1: internal.item_operator_1.take().unwrap_or_default().into(),
+                operator_2: internal.item_operator_2.take().unwrap_or_default().into(),
+                comparison: internal.item_operator_3.take().unwrap_or_default().into(),
+                true_group: internal.target_group.take().unwrap_or_default(),
+                false_group: internal.secondary_group.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::CAMERA_ZOOM_TRIGGER => ObjectData::CameraZoomTrigger(CameraZoomTrigger {
+                zoom: internal.zoom.take().unwrap_or(1.0),
+                duration: internal.duration.take().unwrap_or_default(),
+                easing: internal.easing.take().unwrap_or_default().into(),
+                easing_rate: internal.easing_rate.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::CAMERA_STATIC_TRIGGER => ObjectData::CameraStaticTrigger(CameraStaticTrigger {
+                target_group: internal.secondary_group.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                easing: internal.easing.take().unwrap_or_default().into(),
+                easing_rate: internal.easing_rate.take().unwrap_or_default(),
+                exit_static: internal.exit_static.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::CAMERA_OFFSET_TRIGGER => ObjectData::CameraOffsetTrigger(CameraOffsetTrigger {
+                offset_x: internal.offset_x.take().unwrap_or_default(),
+                offset_y: internal.offset_y.take().unwrap_or_default(),
+                duration: internal.duration.take().unwrap_or_default(),
+                easing: internal.easing.take().unwrap_or_default().into(),
+                easing_rate: internal.easing_rate.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::CAMERA_MODE_TRIGGER => ObjectData::CameraModeTrigger(CameraModeTrigger {
+                free_mode: internal.free_mode.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::CAMERA_EDGE_TRIGGER => ObjectData::CameraEdgeTrigger(CameraEdgeTrigger {
+                target_group: internal.target_group.take().unwrap_or_default(),
+                edge: internal.camera_edge.take().unwrap_or_default().into(),
+                activation: internal.take_activation(),
+            }),
+            ids::SONG_TRIGGER => ObjectData::SongTrigger(SongTrigger {
+                song_id: internal.sound_id.take().unwrap_or_default(),
+                volume: internal.volume.take().unwrap_or(1.0),
+                speed: internal.sound_speed.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::SFX_TRIGGER => ObjectData::SfxTrigger(SfxTrigger {
+                sfx_id: internal.sound_id.take().unwrap_or_default(),
+                volume: internal.volume.take().unwrap_or(1.0),
+                speed: internal.sound_speed.take().unwrap_or_default(),
+                activation: internal.take_activation(),
+            }),
+            ids::TIME_WARP_TRIGGER => ObjectData::TimeWarpTrigger(TimeWarpTrigger {
+                time_modifier: internal.time_modifier.take().unwrap_or(1.0),
+                activation: internal.take_activation(),
+            }),
+            ids::GRAVITY_TRIGGER => ObjectData::GravityTrigger(GravityTrigger {
+                gravity: internal.gravity.take().unwrap_or(1.0),
+                activation: internal.take_activation(),
+            }),
+            id => {
+                if let Some(shader) = Shader::from_object_id(id) {
+                    ObjectData::ShaderTrigger(ShaderTrigger {
+                        shader,
+                        duration: internal.duration.take().unwrap_or_default(),
+                        easing: internal.easing.take().unwrap_or_default().into(),
+                        easing_rate: internal.easing_rate.take().unwrap_or_default(),
+                        activation: internal.take_activation(),
+                    })
+                } else if let Some(pad) = Pad::from_object_id(id) {
+                    ObjectData::Pad {
+                        pad,
+                        multi_activate: internal.orb_multi_activate.take().unwrap_or_default(),
+                    }
+                } else if let Some(orb) = Orb::from_object_id(id) {
+                    ObjectData::Orb {
+                        orb,
+                        multi_activate: internal.orb_multi_activate.take().unwrap_or_default(),
+                    }
+                } else if let Some(orb) = DashOrb::from_object_id(id) {
+                    ObjectData::DashOrb {
+                        orb,
+                        multi_activate: internal.orb_multi_activate.take().unwrap_or_default(),
+                    }
+                } else if let Some(coin) = Coin::from_object_id(id) {
+                    ObjectData::Coin { coin }
+                } else if let Some(effect) = AreaEffect::from_object_id(id) {
+                    ObjectData::AreaTrigger(AreaTrigger {
+                        effect,
+                        target_group: internal.target_group.take().unwrap_or_default(),
+                        center_group: internal.secondary_group.take().unwrap_or_default(),
+                        activation: internal.take_activation(),
+                    })
+                } else {
+                    ObjectData::Unknown
+                }
+            },
         };
 
-        Ok(LevelObject {
+        let mut object = LevelObject {
             id: internal.id,
             x: internal.x,
             y: internal.y,
-            flipped_x: internal.flipped_x,
-            flipped_y: internal.flipped_y,
-            rotation: internal.rotation,
+            flipped_x: internal.flipped_x.take().unwrap_or_default(),
+            flipped_y: internal.flipped_y.take().unwrap_or_default(),
+            rotation: internal.rotation.take().unwrap_or_default(),
+            scale: internal.scale.take().unwrap_or(1.0),
+            scale_x: internal.scale_x.take().unwrap_or(1.0),
+            scale_y: internal.scale_y.take().unwrap_or(1.0),
+            groups: internal.groups.take().map(|groups| groups.0).unwrap_or_default(),
+            group_parent: internal.group_parent.take().unwrap_or_default(),
+            linked_group: internal.linked_group.take().unwrap_or_default(),
+            editor_layer: internal.editor_layer.take().unwrap_or_default(),
+            editor_layer_2: internal.editor_layer_2.take().unwrap_or_default(),
+            z_layer: internal.z_layer.take().unwrap_or_default().into(),
+            z_order: internal.z_order.take().unwrap_or_default(),
+            main_color: internal.main_color.take().unwrap_or_default(),
+            detail_color: internal.detail_color.take().unwrap_or_default(),
+            main_hsv: match internal.main_hsv_enabled.take() {
+                Some(true) => Some(internal.main_hsv.take().map(|hsv| hsv.0).unwrap_or_default()),
+                _ => None,
+            },
+            detail_hsv: match internal.detail_hsv_enabled.take() {
+                Some(true) => Some(internal.detail_hsv.take().map(|hsv| hsv.0).unwrap_or_default()),
+                _ => None,
+            },
+            dont_fade: internal.dont_fade.take().unwrap_or_default(),
+            dont_enter: internal.dont_enter.take().unwrap_or_default(),
+            no_glow: internal.no_glow.take().unwrap_or_default(),
+            high_detail: internal.high_detail.take().unwrap_or_default(),
             metadata,
-        })
+            unknown_properties: UnknownProperties::default(),
+        };
+
+        internal.retain_unconsumed().map_err(D::Error::custom)?;
+        object.unknown_properties = internal.unknown;
+
+        Ok(object)
     }
 
     fn dash_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut internal = self.to_internal::<S::Error>()?;
+
+        internal.unknown = self.unknown_properties.clone();
+        internal.serialize(serializer)
+    }
+}
+
+impl LevelObject {
+    /// Converts this object into its internal representation, without its unknown properties
+    fn to_internal<E: serde::ser::Error>(&self) -> Result<InternalLevelObject, E> {
         let mut internal = InternalLevelObject {
             id: self.id,
             x: self.x,
             y: self.y,
-            flipped_x: self.flipped_x,
-            flipped_y: self.flipped_y,
-            rotation: self.rotation,
+            flipped_x: non_default(self.flipped_x, false),
+            flipped_y: non_default(self.flipped_y, false),
+            rotation: non_default(self.rotation, 0.0),
+            scale: non_default(self.scale, 1.0),
+            scale_x: non_default(self.scale_x, 1.0),
+            scale_y: non_default(self.scale_y, 1.0),
+            groups: Some(DotSeparated(self.groups.clone())).filter(|groups| !groups.0.is_empty()),
+            group_parent: non_default(self.group_parent, false),
+            linked_group: non_default(self.linked_group, 0),
+            editor_layer: non_default(self.editor_layer, 0),
+            editor_layer_2: non_default(self.editor_layer_2, 0),
+            z_layer: non_default(self.z_layer.into(), 0),
+            z_order: non_default(self.z_order, 0),
+            main_color: non_default(self.main_color, 0),
+            detail_color: non_default(self.detail_color, 0),
+            main_hsv_enabled: self.main_hsv.map(|_| true),
+            detail_hsv_enabled: self.detail_hsv.map(|_| true),
+            main_hsv: self.main_hsv.map(Nested),
+            detail_hsv: self.detail_hsv.map(Nested),
+            dont_fade: non_default(self.dont_fade, false),
+            dont_enter: non_default(self.dont_enter, false),
+            no_glow: non_default(self.no_glow, false),
+            high_detail: non_default(self.high_detail, false),
             ..InternalLevelObject::default()
         };
 
-        match self.metadata {
+        match &self.metadata {
             ObjectData::None | ObjectData::Unknown => {},
+            ObjectData::StartPos(start_pos) => {
+                internal.start_game_mode = Some(start_pos.game_mode.into());
+                internal.start_mini = Some(start_pos.mini);
+                internal.start_speed = Some(start_pos.speed.into());
+                internal.start_dual = Some(start_pos.dual);
+                internal.start_gravity_inverted = Some(start_pos.gravity_inverted);
+                internal.start_disabled = non_default(start_pos.disabled, false);
+            },
             ObjectData::SpeedPortal { checked, .. } => {
-                internal.checked = checked;
+                internal.checked = Some(*checked);
+            },
+            // The game only writes the "checked" flag for portals into game modes whose movement is
+            // bounded by a floor and ceiling
+            ObjectData::GameModePortal {
+                checked,
+                game_mode: GameMode::Cube | GameMode::Robot,
+            } => {
+                internal.checked = non_default(*checked, false);
+            },
+            ObjectData::GameModePortal { checked, .. } => {
+                internal.checked = Some(*checked);
+            },
+            ObjectData::SizePortal { .. }
+            | ObjectData::GravityPortal { .. }
+            | ObjectData::MirrorPortal { .. }
+            | ObjectData::DualPortal { .. }
+            | ObjectData::Coin { .. } => {},
+            ObjectData::TeleportPortal { offset_y } => {
+                internal.teleport_offset = Some(*offset_y);
+            },
+            ObjectData::Text { text, kerning } => {
+                internal.text = Some(text.as_unprocessed().map_err(E::custom)?.into_owned());
+                internal.kerning = non_default(*kerning, 0);
+            },
+            ObjectData::Pad { multi_activate, .. }
+            | ObjectData::Orb { multi_activate, .. }
+            | ObjectData::DashOrb { multi_activate, .. } => {
+                internal.orb_multi_activate = non_default(*multi_activate, false);
+            },
+            // The game always writes some of the trigger properties, even if they have their default
+            // value. We do the same, to stay as close to the original level string as possible.
+            ObjectData::MoveTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.duration = Some(trigger.duration);
+                internal.offset_x = Some(trigger.offset_x);
+                internal.offset_y = Some(trigger.offset_y);
+                internal.easing = Some(trigger.easing.into());
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.lock_to_player_x = non_default(trigger.lock_to_player_x, false);
+                internal.lock_to_player_y = non_default(trigger.lock_to_player_y, false);
+                internal.use_target = non_default(trigger.use_target, false);
+                internal.secondary_group = non_default(trigger.target_position_group, 0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::RotateTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.secondary_group = Some(trigger.center_group);
+                internal.duration = Some(trigger.duration);
+                internal.degrees = Some(trigger.degrees);
+                internal.full_rotations = Some(trigger.full_rotations);
+                internal.lock_rotation = Some(trigger.lock_rotation);
+                internal.easing = Some(trigger.easing.into());
+                internal.easing_rate = Some(trigger.easing_rate);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::PulseTrigger(trigger) => {
+                match trigger.target {
+                    PulseTarget::ColorChannel(channel) => internal.target_group = Some(channel),
+                    PulseTarget::Group(group) => {
+                        internal.target_group = Some(group);
+                        internal.pulse_group = Some(true);
+                    },
+                }
+                match trigger.mode {
+                    PulseMode::Color { red, green, blue } => {
+                        internal.red = Some(red);
+                        internal.green = Some(green);
+                        internal.blue = Some(blue);
+                    },
+                    PulseMode::Hsv { copied_channel, hsv } => {
+                        internal.pulse_hsv_mode = Some(true);
+                        internal.copied_color = non_default(copied_channel, 0);
+                        internal.copied_hsv = non_default(hsv, HsvValue::default()).map(Nested);
+                    },
+                }
+                internal.fade_in = non_default(trigger.fade_in, 0.0);
+                internal.hold = non_default(trigger.hold, 0.0);
+                internal.fade_out = non_default(trigger.fade_out, 0.0);
+                internal.exclusive = non_default(trigger.exclusive, false);
+                internal.main_only = non_default(trigger.main_only, false);
+                internal.detail_only = non_default(trigger.detail_only, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::AlphaTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.duration = Some(trigger.duration);
+                internal.opacity = Some(trigger.opacity);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::ColorTrigger(trigger) => {
+                internal.target_color = non_default(trigger.target_channel, 1);
+                internal.red = Some(trigger.red);
+                internal.green = Some(trigger.green);
+                internal.blue = Some(trigger.blue);
+                internal.duration = Some(trigger.duration);
+                internal.opacity = Some(trigger.opacity);
+                internal.blending = non_default(trigger.blending, false);
+                match trigger.player_color {
+                    PlayerColor::Player1 => internal.player_color_1 = Some(true),
+                    PlayerColor::Player2 => internal.player_color_2 = Some(true),
+                    _ => {},
+                }
+                internal.copied_color = non_default(trigger.copied_channel, 0);
+                internal.copied_hsv = non_default(trigger.copied_hsv, HsvValue::default()).map(Nested);
+                internal.copy_opacity = non_default(trigger.copy_opacity, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::FollowTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.secondary_group = Some(trigger.follow_group);
+                internal.duration = Some(trigger.duration);
+                internal.x_modifier = Some(trigger.x_modifier);
+                internal.y_modifier = Some(trigger.y_modifier);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::ShakeTrigger(trigger) => {
+                internal.duration = Some(trigger.duration);
+                internal.strength = Some(trigger.strength);
+                internal.interval = non_default(trigger.interval, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::SpawnTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.delay = non_default(trigger.delay, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::ToggleTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.activate_group = non_default(trigger.activate_group, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CountTrigger(trigger) => {
+                internal.item_1 = Some(trigger.item_id);
+                internal.count = Some(trigger.target_count);
+                internal.target_group = Some(trigger.target_group);
+                internal.activate_group = non_default(trigger.activate_group, false);
+                internal.multi_activate = non_default(trigger.multi_activate, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::InstantCountTrigger(trigger) => {
+                internal.item_1 = Some(trigger.item_id);
+                internal.count = Some(trigger.target_count);
+                internal.count_comparison = non_default(trigger.comparison.into(), 0);
+                internal.target_group = Some(trigger.target_group);
+                internal.activate_group = non_default(trigger.activate_group, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::PickupTrigger(trigger) => {
+                internal.item_1 = Some(trigger.item_id);
+                internal.count = Some(trigger.count);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CollisionTrigger(trigger) => {
+                internal.item_1 = Some(trigger.block_a);
+                internal.item_2 = Some(trigger.block_b);
+                internal.target_group = Some(trigger.target_group);
+                internal.activate_group = non_default(trigger.activate_group, false);
+                internal.trigger_on_exit = non_default(trigger.trigger_on_exit, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::TouchTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.hold_mode = non_default(trigger.hold_mode, false);
+                internal.toggle_mode = non_default(trigger.toggle_mode.into(), 0);
+                internal.dual_mode = non_default(trigger.dual_mode, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::RandomTrigger(trigger) => {
+                internal.target_group = Some(trigger.group_1);
+                internal.secondary_group = Some(trigger.group_2);
+                internal.duration = Some(trigger.chance);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::SequenceTrigger(trigger) => {
+                internal.sequence = Some(DotSeparated(
+                    trigger.steps.iter().flat_map(|step| [step.group, step.count]).collect(),
+                ));
+                internal.min_interval = non_default(trigger.min_interval, 0.0);
+                internal.sequence_mode = non_default(trigger.mode.into(), 0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::ItemEditTrigger(trigger) => {
+                internal.item_1 = Some(trigger.item_1.id);
+                internal.item_1_kind = non_default(trigger.item_1.kind.into(), 1);
+                internal.item_2 = Some(trigger.item_2.id);
+                internal.item_2_kind = non_default(trigger.item_2.kind.into(), 1);
+                internal.target_group = Some(trigger.target.id);
+                internal.target_kind = non_default(trigger.target.kind.into(), 1);
+                internal.modifier_1 = non_default(trigger.modifier, 0.0);
+                internal.item_operator_1 = non_default(trigger.assign_operator.into(), 0);
+                internal.item_operator_2 = non_default(trigger.operator_1.into(), 0);
+                internal.item_operator_3 = non_default(trigger.operator_2.into(), 0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::ItemCompareTrigger(trigger) => {
+                internal.item_1 = Some(trigger.item_1.id);
+                internal.item_1_kind = non_default(trigger.item_1.kind.into(), 1);
+                internal.item_2 = Some(trigger.item_2.id);
+                internal.item_2_kind = non_default(trigger.item_2.kind.into(), 1);
+                internal.modifier_1 = non_default(trigger.modifier_1, 0.0);
+                internal.modifier_2 = non_default(trigger.modifier_2, 0.0);
+                internal.item_operator_1 = non_default(trigger.operator_1.into(), 0);
+                internal.item_operator_2 = non_default(trigger.operator_2.into(), 0);
+                internal.item_operator_3 = non_default(trigger.comparison.into(), 0);
+                internal.target_group = Some(trigger.true_group);
+                internal.secondary_group = Some(trigger.false_group);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CameraZoomTrigger(trigger) => {
+                internal.zoom = Some(trigger.zoom);
+                internal.duration = Some(trigger.duration);
+                internal.easing = non_default(trigger.easing.into(), 0);
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CameraStaticTrigger(trigger) => {
+                internal.secondary_group = Some(trigger.target_group);
+                internal.duration = Some(trigger.duration);
+                internal.easing = non_default(trigger.easing.into(), 0);
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.exit_static = non_default(trigger.exit_static, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CameraOffsetTrigger(trigger) => {
+                internal.offset_x = non_default(trigger.offset_x, 0.0);
+                internal.offset_y = non_default(trigger.offset_y, 0.0);
+                internal.duration = Some(trigger.duration);
+                internal.easing = non_default(trigger.easing.into(), 0);
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CameraModeTrigger(trigger) => {
+                internal.free_mode = non_default(trigger.free_mode, false);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::CameraEdgeTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.camera_edge = non_default(trigger.edge.into(), 0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::ShaderTrigger(trigger) => {
+                internal.duration = non_default(trigger.duration, 0.0);
+                internal.easing = non_default(trigger.easing.into(), 0);
+                internal.easing_rate = non_default(trigger.easing_rate, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::SongTrigger(trigger) => {
+                internal.sound_id = Some(trigger.song_id);
+                internal.volume = non_default(trigger.volume, 1.0);
+                internal.sound_speed = non_default(trigger.speed, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::SfxTrigger(trigger) => {
+                internal.sound_id = Some(trigger.sfx_id);
+                internal.volume = non_default(trigger.volume, 1.0);
+                internal.sound_speed = non_default(trigger.speed, 0.0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::AreaTrigger(trigger) => {
+                internal.target_group = Some(trigger.target_group);
+                internal.secondary_group = non_default(trigger.center_group, 0);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::TimeWarpTrigger(trigger) => {
+                internal.time_modifier = Some(trigger.time_modifier);
+                internal.set_activation(&trigger.activation);
+            },
+            ObjectData::GravityTrigger(trigger) => {
+                internal.gravity = Some(trigger.gravity);
+                internal.set_activation(&trigger.activation);
             },
         };
 
-        internal.serialize(serializer)
+        Ok(internal)
     }
 }
 
 impl<'de> GJFormat<'de> for LevelObject {
     const DELIMITER: &'static str = ",";
     const MAP_LIKE: bool = true;
+
+    fn from_gj_str(input: &'de str) -> Result<Self, DeError<'de>> {
+        let mut object = Self::dash_deserialize(&mut IndexedDeserializer::new(input, Self::DELIMITER, Self::MAP_LIKE))?;
+        let internal = object.to_internal::<SerError>().map_err(DeError::custom)?;
+        let mut split = input.split(Self::DELIMITER);
+
+        // Known keys that are not written back despite being present were explicitly set to their
+        // default value
+        while let (Some(key), Some(value)) = (split.next(), split.next()) {
+            if !internal.contains_key(key) && object.unknown_properties.get(key).is_none() {
+                object.unknown_properties.record_explicit_default(key, value)
+            }
+        }
+
+        object.unknown_properties.record_layout(input, Self::DELIMITER);
+
+        Ok(object)
+    }
+
+    fn write_gj<W: Write>(&self, writer: W) -> Result<(), SerError> {
+        let mut buffer = Vec::new();
+
+        self.dash_serialize(&mut IndexedSerializer::new(Self::DELIMITER, &mut buffer, Self::MAP_LIKE))?;
+        self.unknown_properties.write_in_layout(&buffer, Self::DELIMITER, writer)
+    }
 }
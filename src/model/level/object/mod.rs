@@ -1,11 +1,37 @@
-use crate::model::level::object::speed::Speed;
+use crate::{
+    model::level::{
+        color::HsvValue,
+        object::{
+            game_mode::GameMode,
+            ids::ObjectInfo,
+            interactive::{Coin, DashOrb, Orb, Pad},
+            portal::GravityChange,
+            speed::Speed,
+            start_pos::StartPos,
+            trigger::{
+                AlphaTrigger, AreaTrigger, CameraEdgeTrigger, CameraModeTrigger, CameraOffsetTrigger, CameraStaticTrigger,
+                CameraZoomTrigger, CollisionTrigger, ColorTrigger, CountTrigger, FollowTrigger, GravityTrigger, InstantCountTrigger,
+                ItemCompareTrigger, ItemEditTrigger, MoveTrigger, PickupTrigger, PulseTrigger, RandomTrigger, RotateTrigger,
+                SequenceTrigger, SfxTrigger, ShaderTrigger, ShakeTrigger, SongTrigger, SpawnTrigger, TimeWarpTrigger, ToggleTrigger,
+                TouchTrigger, TriggerActivation,
+            },
+        },
+        UnknownProperties,
+    },
+    serde::{Base64Decoder, Thunk},
+};
 use serde::{Deserialize, Serialize};
 
+pub mod game_mode;
 pub mod ids;
+pub mod interactive;
 mod internal;
+pub mod portal;
 pub mod speed;
+pub mod start_pos;
+pub mod trigger;
 
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct LevelObject {
     pub id: u16,
     pub x: f32,
@@ -13,13 +39,390 @@ pub struct LevelObject {
     pub flipped_x: bool,
     pub flipped_y: bool,
     pub rotation: f32,
-    // ... other fields they all have ...
+
+    /// The uniform scale factor of this object
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `32`. Since update 2.2, the game additionally stores
+    /// independent scale factors for each axis (see [`LevelObject::scale_x`] and
+    /// [`LevelObject::scale_y`]), which are applied on top of this value.
+    pub scale: f32,
+
+    /// The horizontal scale factor of this object
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `128`
+    pub scale_x: f32,
+
+    /// The vertical scale factor of this object
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `129`
+    pub scale_y: f32,
+
+    /// The ids of the groups this object is part of
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `57`, as a `.`-separated list
+    pub groups: Vec<u16>,
+
+    /// Whether this object is the parent of its group
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `34`
+    pub group_parent: bool,
+
+    /// The id of the group of linked objects this object belongs to, or `0` if it is not linked to
+    /// other objects
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `108`
+    pub linked_group: u32,
+
+    /// The (first) editor layer this object is placed on
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `20`
+    pub editor_layer: u16,
+
+    /// The second editor layer this object is placed on
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `61`
+    pub editor_layer_2: u16,
+
+    /// The z-layer this object is rendered on
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `24`
+    pub z_layer: ZLayer,
+
+    /// The order in which this object is rendered relative to other objects on the same z-layer
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `25`
+    pub z_order: i32,
+
+    /// The id of the color channel used for this object's main color, or `0` if the object uses its
+    /// default color channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `21`
+    pub main_color: u16,
+
+    /// The id of the color channel used for this object's detail color, or `0` if the object uses
+    /// its default color channel
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `22`
+    pub detail_color: u16,
+
+    /// The HSV modification applied to this object's main color, or [`None`] if no modification
+    /// is applied.
+    ///
+    /// ## GD Internals:
+    /// Whether HSV modification is enabled is stored at index `41`, the actual modification at
+    /// index `43`
+    pub main_hsv: Option<HsvValue>,
+
+    /// The HSV modification applied to this object's detail color, or [`None`] if no
+    /// modification is applied.
+    ///
+    /// ## GD Internals:
+    /// Whether HSV modification is enabled is stored at index `42`, the actual modification at
+    /// index `44`
+    pub detail_hsv: Option<HsvValue>,
+
+    /// Whether this object is exempt from the fade effect at the edges of the screen
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `64`
+    pub dont_fade: bool,
+
+    /// Whether this object is exempt from the level's enter effects
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `67`
+    pub dont_enter: bool,
+
+    /// Whether this object's glow is disabled
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `96`
+    pub no_glow: bool,
+
+    /// Whether this object is only shown if the "high detail" option is enabled
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `103`
+    pub high_detail: bool,
+
     pub metadata: ObjectData,
+
+    /// All key-value pairs of this object that dash-rs does not map to any of the above fields, in
+    /// the order they appeared in
+    pub unknown_properties: UnknownProperties,
 }
 
-#[derive(Debug, Clone, PartialEq, Copy, Serialize, Deserialize)]
+impl LevelObject {
+    /// Creates an object with the given id at the given position, with all other properties set
+    /// to the values the editor uses for newly placed objects
+    pub fn new(id: u16, x: f32, y: f32) -> LevelObject {
+        LevelObject {
+            id,
+            x,
+            y,
+            flipped_x: false,
+            flipped_y: false,
+            rotation: 0.0,
+            scale: 1.0,
+            scale_x: 1.0,
+            scale_y: 1.0,
+            groups: Vec::new(),
+            group_parent: false,
+            linked_group: 0,
+            editor_layer: 0,
+            editor_layer_2: 0,
+            z_layer: ZLayer::Default,
+            z_order: 0,
+            main_color: 0,
+            detail_color: 0,
+            main_hsv: None,
+            detail_hsv: None,
+            dont_fade: false,
+            dont_enter: false,
+            no_glow: false,
+            high_detail: false,
+            metadata: ObjectData::Unknown,
+            unknown_properties: UnknownProperties::default(),
+        }
+    }
+
+    /// Looks up this object's id in the object catalogue, see [`ids::lookup`]
+    pub fn info(&self) -> Option<&'static ObjectInfo> {
+        ids::lookup(self.id)
+    }
+
+    /// Returns the category this object falls into
+    ///
+    /// Objects missing from the [catalogue](ids) are reported as triggers if they are activated
+    /// like one, and as decoration otherwise.
+    pub fn category(&self) -> ObjectCategory {
+        match self.info() {
+            Some(info) => info.category,
+            None if self.metadata.activation().is_some() => ObjectCategory::Trigger,
+            None => ObjectCategory::Decoration,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ObjectData {
     None,
     Unknown,
-    SpeedPortal { checked: bool, speed: Speed },
+    StartPos(StartPos),
+    SpeedPortal {
+        checked: bool,
+        speed: Speed,
+    },
+    GameModePortal {
+        checked: bool,
+        game_mode: GameMode,
+    },
+    SizePortal {
+        mini: bool,
+    },
+    GravityPortal {
+        gravity: GravityChange,
+    },
+    MirrorPortal {
+        mirrored: bool,
+    },
+    DualPortal {
+        dual: bool,
+    },
+    /// A teleport portal. `offset_y` is the vertical distance between the entry portal and the
+    /// exit portal the player is teleported to.
+    TeleportPortal {
+        offset_y: f32,
+    },
+    /// A text object. `kerning` is the additional spacing between characters.
+    Text {
+        text: Thunk<'static, Base64Decoder>,
+        kerning: i32,
+    },
+    /// A jump pad. If `multi_activate` is set, the pad can be used more than once.
+    Pad {
+        pad: Pad,
+        multi_activate: bool,
+    },
+    /// An orb other than a dash orb. If `multi_activate` is set, the orb can be used more than once.
+    Orb {
+        orb: Orb,
+        multi_activate: bool,
+    },
+    /// A dash orb. If `multi_activate` is set, the orb can be used more than once.
+    DashOrb {
+        orb: DashOrb,
+        multi_activate: bool,
+    },
+    /// A secret or user coin
+    Coin {
+        coin: Coin,
+    },
+    MoveTrigger(MoveTrigger),
+    RotateTrigger(RotateTrigger),
+    PulseTrigger(PulseTrigger),
+    AlphaTrigger(AlphaTrigger),
+    ColorTrigger(ColorTrigger),
+    FollowTrigger(FollowTrigger),
+    ShakeTrigger(ShakeTrigger),
+    SpawnTrigger(SpawnTrigger),
+    ToggleTrigger(ToggleTrigger),
+    CountTrigger(CountTrigger),
+    InstantCountTrigger(InstantCountTrigger),
+    PickupTrigger(PickupTrigger),
+    CollisionTrigger(CollisionTrigger),
+    TouchTrigger(TouchTrigger),
+    RandomTrigger(RandomTrigger),
+    SequenceTrigger(SequenceTrigger),
+    ItemEditTrigger(ItemEditTrigger),
+    ItemCompareTrigger(ItemCompareTrigger),
+    CameraZoomTrigger(CameraZoomTrigger),
+    CameraStaticTrigger(CameraStaticTrigger),
+    CameraOffsetTrigger(CameraOffsetTrigger),
+    CameraModeTrigger(CameraModeTrigger),
+    CameraEdgeTrigger(CameraEdgeTrigger),
+    ShaderTrigger(ShaderTrigger),
+    SongTrigger(SongTrigger),
+    SfxTrigger(SfxTrigger),
+    AreaTrigger(AreaTrigger),
+    TimeWarpTrigger(TimeWarpTrigger),
+    GravityTrigger(GravityTrigger),
+}
+
+impl ObjectData {
+    /// Returns how the object is activated if it is a trigger, or [`None`] otherwise
+    pub fn activation(&self) -> Option<&TriggerActivation> {
+        match self {
+            ObjectData::None
+            | ObjectData::Unknown
+            | ObjectData::StartPos(_)
+            | ObjectData::SpeedPortal { .. }
+            | ObjectData::GameModePortal { .. }
+            | ObjectData::SizePortal { .. }
+            | ObjectData::GravityPortal { .. }
+            | ObjectData::MirrorPortal { .. }
+            | ObjectData::DualPortal { .. }
+            | ObjectData::TeleportPortal { .. }
+            | ObjectData::Text { .. }
+            | ObjectData::Pad { .. }
+            | ObjectData::Orb { .. }
+            | ObjectData::DashOrb { .. }
+            | ObjectData::Coin { .. } => None,
+            ObjectData::MoveTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::RotateTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::PulseTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::AlphaTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::ColorTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::FollowTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::ShakeTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::SpawnTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::ToggleTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CountTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::InstantCountTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::PickupTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CollisionTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::TouchTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::RandomTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::SequenceTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::ItemEditTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::ItemCompareTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CameraZoomTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CameraStaticTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CameraOffsetTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CameraModeTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::CameraEdgeTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::ShaderTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::SongTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::SfxTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::AreaTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::TimeWarpTrigger(trigger) => Some(&trigger.activation),
+            ObjectData::GravityTrigger(trigger) => Some(&trigger.activation),
+        }
+    }
+}
+
+/// Coarse classification of objects by how they interact with the player
+///
+/// Objects are classified through the object catalogue in [`ids`]. Triggers missing from the
+/// catalogue are still recognized through their [`ObjectData`], everything else that is missing is
+/// treated as decoration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ObjectCategory {
+    Decoration,
+    Solid,
+    Hazard,
+    Trigger,
+    Portal,
+
+    /// Orbs, pads and coins
+    Interactive,
+}
+
+/// Enum modelling the different z-layers an object can be rendered on
+///
+/// Layers prefixed with `B` are rendered behind the player, layers prefixed with `T` in front of
+/// it. Higher numbers are further away from the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ZLayer {
+    /// The object is rendered on its default layer
+    #[default]
+    Default,
+    B5,
+    B4,
+    B3,
+    B2,
+    B1,
+    T1,
+    T2,
+    T3,
+    T4,
+    Unknown(i8),
+}
+
+impl From<i8> for ZLayer {
+    fn from(value: i8) -> Self {
+        match value {
+            0 => ZLayer::Default,
+            -5 => ZLayer::B5,
+            -3 => ZLayer::B4,
+            -1 => ZLayer::B3,
+            1 => ZLayer::B2,
+            3 => ZLayer::B1,
+            5 => ZLayer::T1,
+            7 => ZLayer::T2,
+            9 => ZLayer::T3,
+            11 => ZLayer::T4,
+            unknown => ZLayer::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ZLayer> for i8 {
+    fn from(layer: ZLayer) -> Self {
+        match layer {
+            ZLayer::Default => 0,
+            ZLayer::B5 => -5,
+            ZLayer::B4 => -3,
+            ZLayer::B3 => -1,
+            ZLayer::B2 => 1,
+            ZLayer::B1 => 3,
+            ZLayer::T1 => 5,
+            ZLayer::T2 => 7,
+            ZLayer::T3 => 9,
+            ZLayer::T4 => 11,
+            ZLayer::Unknown(unknown) => unknown,
+        }
+    }
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Enum modelling how a gravity portal changes the player's gravity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GravityChange {
+    /// The player's gravity is reset to pointing downwards (the blue gravity portal)
+    Normal,
+
+    /// The player's gravity is set to pointing upwards (the yellow gravity portal)
+    Inverted,
+
+    /// The player's gravity is flipped, whatever its current direction (the green gravity portal)
+    Toggle,
+}
+
+impl GravityChange {
+    /// Applies this change to the given gravity state, returning whether gravity is inverted
+    /// afterwards
+    pub fn apply(self, inverted: bool) -> bool {
+        match self {
+            GravityChange::Normal => false,
+            GravityChange::Inverted => true,
+            GravityChange::Toggle => !inverted,
+        }
+    }
+}
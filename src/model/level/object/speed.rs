@@ -28,11 +28,14 @@ impl From<Speed> for f32 {
     }
 }
 
+/// Converts the value used for the starting speed in a level's metadata section into a [`Speed`]
+///
+/// Note that `0` denotes normal speed here, while slow speed is encoded as `1`.
 impl From<u8> for Speed {
     fn from(value: u8) -> Self {
         match value {
-            0 => Speed::Slow,
-            1 => Speed::Normal,
+            0 => Speed::Normal,
+            1 => Speed::Slow,
             2 => Speed::Medium,
             3 => Speed::Fast,
             4 => Speed::VeryFast,
@@ -44,8 +47,8 @@ impl From<u8> for Speed {
 impl From<Speed> for u8 {
     fn from(speed: Speed) -> Self {
         match speed {
-            Speed::Slow => 0,
-            Speed::Normal => 1,
+            Speed::Normal => 0,
+            Speed::Slow => 1,
             Speed::Medium => 2,
             Speed::Fast => 3,
             Speed::VeryFast => 4,
@@ -0,0 +1,51 @@
+use crate::model::level::{
+    gameplay::GameplayState,
+    object::{game_mode::GameMode, speed::Speed},
+};
+use serde::{Deserialize, Serialize};
+
+/// A start position, which allows playtesting a level from somewhere other than its beginning
+///
+/// Start positions carry their own copy of the relevant parts of the level header, describing the
+/// state the player is in when starting from them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct StartPos {
+    /// ## GD Internals:
+    /// This value is provided at index `kA4`
+    pub speed: Speed,
+
+    /// ## GD Internals:
+    /// This value is provided at index `kA2`
+    pub game_mode: GameMode,
+
+    /// ## GD Internals:
+    /// This value is provided at index `kA3`
+    pub mini: bool,
+
+    /// ## GD Internals:
+    /// This value is provided at index `kA8`
+    pub dual: bool,
+
+    /// ## GD Internals:
+    /// This value is provided at index `kA11`
+    pub gravity_inverted: bool,
+
+    /// Whether this start position is disabled, meaning the game ignores it when playtesting
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `kA21`
+    pub disabled: bool,
+}
+
+impl StartPos {
+    /// Returns the state the player is in when starting from this start position
+    pub fn gameplay_state(&self) -> GameplayState {
+        GameplayState {
+            game_mode: self.game_mode,
+            mini: self.mini,
+            gravity_inverted: self.gravity_inverted,
+            dual: self.dual,
+            mirrored: false,
+        }
+    }
+}
@@ -0,0 +1,1377 @@
+//! Module containing the types describing the different triggers that can be placed in a level
+
+use crate::model::level::{
+    color::{HsvValue, PlayerColor},
+    object::ids,
+};
+use serde::{Deserialize, Serialize};
+
+/// Struct describing how a trigger gets activated, which is the same for all types of triggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct TriggerActivation {
+    /// Whether the trigger is activated by the player touching it, instead of by the player
+    /// passing its x-position
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `11`
+    pub touch_triggered: bool,
+
+    /// Whether the trigger is only activated by spawn triggers (or other triggers capable of
+    /// spawning groups), and not by the player at all
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `62`
+    pub spawn_triggered: bool,
+
+    /// Whether a spawn-triggered trigger can be activated multiple times
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `87`
+    pub multi_triggered: bool,
+}
+
+/// Enum modelling the different easing functions triggers that move or rotate objects can use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Easing {
+    #[default]
+    None,
+    EaseInOut,
+    EaseIn,
+    EaseOut,
+    ElasticInOut,
+    ElasticIn,
+    ElasticOut,
+    BounceInOut,
+    BounceIn,
+    BounceOut,
+    ExponentialInOut,
+    ExponentialIn,
+    ExponentialOut,
+    SineInOut,
+    SineIn,
+    SineOut,
+    BackInOut,
+    BackIn,
+    BackOut,
+    Unknown(u8),
+}
+
+impl From<u8> for Easing {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Easing::None,
+            1 => Easing::EaseInOut,
+            2 => Easing::EaseIn,
+            3 => Easing::EaseOut,
+            4 => Easing::ElasticInOut,
+            5 => Easing::ElasticIn,
+            6 => Easing::ElasticOut,
+            7 => Easing::BounceInOut,
+            8 => Easing::BounceIn,
+            9 => Easing::BounceOut,
+            10 => Easing::ExponentialInOut,
+            11 => Easing::ExponentialIn,
+            12 => Easing::ExponentialOut,
+            13 => Easing::SineInOut,
+            14 => Easing::SineIn,
+            15 => Easing::SineOut,
+            16 => Easing::BackInOut,
+            17 => Easing::BackIn,
+            18 => Easing::BackOut,
+            unknown => Easing::Unknown(unknown),
+        }
+    }
+}
+
+impl From<Easing> for u8 {
+    fn from(easing: Easing) -> Self {
+        match easing {
+            Easing::None => 0,
+            Easing::EaseInOut => 1,
+            Easing::EaseIn => 2,
+            Easing::EaseOut => 3,
+            Easing::ElasticInOut => 4,
+            Easing::ElasticIn => 5,
+            Easing::ElasticOut => 6,
+            Easing::BounceInOut => 7,
+            Easing::BounceIn => 8,
+            Easing::BounceOut => 9,
+            Easing::ExponentialInOut => 10,
+            Easing::ExponentialIn => 11,
+            Easing::ExponentialOut => 12,
+            Easing::SineInOut => 13,
+            Easing::SineIn => 14,
+            Easing::SineOut => 15,
+            Easing::BackInOut => 16,
+            Easing::BackIn => 17,
+            Easing::BackOut => 18,
+            Easing::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// A move trigger, which moves all objects in some group by some offset
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MoveTrigger {
+    /// The id of the group that is moved
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// The time the movement takes, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// The horizontal offset, in units (one block is 30 units wide)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `28`
+    pub offset_x: f32,
+
+    /// The vertical offset, in units (one block is 30 units high)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `29`
+    pub offset_y: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `30`
+    pub easing: Easing,
+
+    /// ## GD Internals:
+    /// This value is provided at index `85`
+    pub easing_rate: f32,
+
+    /// Whether the target group additionally follows the player's horizontal movement
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `58`
+    pub lock_to_player_x: bool,
+
+    /// Whether the target group additionally follows the player's vertical movement
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `59`
+    pub lock_to_player_y: bool,
+
+    /// Whether the target group is moved to the position of
+    /// [`MoveTrigger::target_position_group`], instead of by [`MoveTrigger::offset_x`] and
+    /// [`MoveTrigger::offset_y`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `100`
+    pub use_target: bool,
+
+    /// The id of the group whose position the target group moves to if
+    /// [`MoveTrigger::use_target`] is set
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub target_position_group: u16,
+
+    pub activation: TriggerActivation,
+}
+
+/// A rotate trigger, which rotates all objects in some group around the center of another group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RotateTrigger {
+    /// The id of the group that is rotated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// The id of the group around whose center the target group is rotated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub center_group: u16,
+
+    /// The time the rotation takes, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// The rotation, in degrees, on top of [`RotateTrigger::full_rotations`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `68`
+    pub degrees: f32,
+
+    /// The number of full 360° rotations
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `69`
+    pub full_rotations: i32,
+
+    /// Whether the objects in the target group keep their orientation while moving around the
+    /// center group
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `70`
+    pub lock_rotation: bool,
+
+    /// ## GD Internals:
+    /// This value is provided at index `30`
+    pub easing: Easing,
+
+    /// ## GD Internals:
+    /// This value is provided at index `85`
+    pub easing_rate: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum describing what a [`PulseTrigger`] pulses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PulseTarget {
+    /// The color channel with the given id is pulsed
+    ColorChannel(u16),
+
+    /// All objects in the group with the given id are pulsed
+    Group(u16),
+}
+
+/// Enum describing which color a [`PulseTrigger`] pulses its target with
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum PulseMode {
+    /// The target is pulsed with the given color
+    ///
+    /// ## GD Internals:
+    /// The color's components are provided at indices `7`, `8` and `9`
+    Color { red: u8, green: u8, blue: u8 },
+
+    /// The target is pulsed with the color of some color channel, modified by some HSV value
+    ///
+    /// ## GD Internals:
+    /// This mode is selected by setting index `48` to `1`. The channel id is provided at index `50`,
+    /// the HSV modification at index `49`.
+    Hsv { copied_channel: u16, hsv: HsvValue },
+}
+
+/// A pulse trigger, which temporarily changes the color of a color channel or group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PulseTrigger {
+    /// ## GD Internals:
+    /// The target's id is provided at index `51`. Index `52` is `1` if the target is a group.
+    pub target: PulseTarget,
+
+    pub mode: PulseMode,
+
+    /// The time it takes to fade to the pulse color, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `45`
+    pub fade_in: f32,
+
+    /// The time the pulse color is held, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `46`
+    pub hold: f32,
+
+    /// The time it takes to fade back from the pulse color, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `47`
+    pub fade_out: f32,
+
+    /// Whether this pulse overrides all other pulses on the same target
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `86`
+    pub exclusive: bool,
+
+    /// Whether only the main color of objects in the target group is pulsed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `65`
+    pub main_only: bool,
+
+    /// Whether only the detail color of objects in the target group is pulsed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `66`
+    pub detail_only: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// An alpha trigger, which changes the opacity of all objects in some group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AlphaTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// The time the change in opacity takes, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// The opacity the target group is faded to, between `0.0` and `1.0`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `35`
+    pub opacity: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A color trigger, which changes the color of some color channel
+///
+/// Note that this only covers the generic color trigger introduced in update 2.0 (object id
+/// `899`). The older, channel specific color triggers are still represented as
+/// [`ObjectData::Unknown`](super::ObjectData::Unknown).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ColorTrigger {
+    /// The id of the color channel whose color is changed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `23`. If it is not present, channel `1` is targeted.
+    pub target_channel: u16,
+
+    /// ## GD Internals:
+    /// This value is provided at index `7`
+    pub red: u8,
+
+    /// ## GD Internals:
+    /// This value is provided at index `8`
+    pub green: u8,
+
+    /// ## GD Internals:
+    /// This value is provided at index `9`
+    pub blue: u8,
+
+    /// The time the change in color takes, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// The opacity the target channel is changed to, between `0.0` and `1.0`
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `35`
+    pub opacity: f32,
+
+    /// Whether the target channel uses additive blending after the change
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `17`
+    pub blending: bool,
+
+    /// The player color the target channel is changed to, if any
+    ///
+    /// ## GD Internals:
+    /// Index `15` is set to `1` to use the first player color, index `16` to use the second one.
+    pub player_color: PlayerColor,
+
+    /// The id of the color channel whose color the target channel is changed to, or `0` if the
+    /// target channel is changed to [`ColorTrigger::red`], [`ColorTrigger::green`] and
+    /// [`ColorTrigger::blue`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `50`
+    pub copied_channel: u16,
+
+    /// The HSV modification applied to the color of [`ColorTrigger::copied_channel`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `49`
+    pub copied_hsv: HsvValue,
+
+    /// Whether the target channel also copies the opacity of [`ColorTrigger::copied_channel`]
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `60`
+    pub copy_opacity: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// A follow trigger, which makes all objects in some group follow the movement of another group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FollowTrigger {
+    /// The id of the group that follows
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// The id of the group that is followed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub follow_group: u16,
+
+    /// The time for which the target group follows, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// The factor by which the horizontal movement of the followed group is multiplied
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `72`
+    pub x_modifier: f32,
+
+    /// The factor by which the vertical movement of the followed group is multiplied
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `73`
+    pub y_modifier: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A shake trigger, which shakes the camera
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ShakeTrigger {
+    /// The time the camera shakes, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `75`
+    pub strength: f32,
+
+    /// The time between two shakes, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `84`
+    pub interval: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A spawn trigger, which activates all triggers in some group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpawnTrigger {
+    /// The id of the group whose triggers are activated
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// The time between this trigger being activated and it activating the target group, in
+    /// seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `63`
+    pub delay: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A toggle trigger, which enables or disables all objects in some group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToggleTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// Whether the target group is enabled (instead of disabled)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `56`
+    pub activate_group: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// A count trigger, which toggles some group once an item counter reaches some value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CountTrigger {
+    /// The id of the item counter that is watched
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `80`
+    pub item_id: u16,
+
+    /// The value the item counter has to reach
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `77`
+    pub target_count: i32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// Whether the target group is enabled (instead of disabled)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `56`
+    pub activate_group: bool,
+
+    /// Whether the target group is toggled every time the counter reaches the target count,
+    /// instead of only the first time
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `104`
+    pub multi_activate: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum modelling how an [`InstantCountTrigger`] compares an item counter to its target count
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CountComparison {
+    #[default]
+    Equals,
+    Larger,
+    Smaller,
+    Unknown(u8),
+}
+
+impl From<u8> for CountComparison {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => CountComparison::Equals,
+            1 => CountComparison::Larger,
+            2 => CountComparison::Smaller,
+            unknown => CountComparison::Unknown(unknown),
+        }
+    }
+}
+
+impl From<CountComparison> for u8 {
+    fn from(comparison: CountComparison) -> Self {
+        match comparison {
+            CountComparison::Equals => 0,
+            CountComparison::Larger => 1,
+            CountComparison::Smaller => 2,
+            CountComparison::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// An instant count trigger, which toggles some group if an item counter satisfies some comparison
+/// at the time the trigger is activated
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstantCountTrigger {
+    /// The id of the item counter that is checked
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `80`
+    pub item_id: u16,
+
+    /// The value the item counter is compared to
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `77`
+    pub target_count: i32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `88`
+    pub comparison: CountComparison,
+
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// Whether the target group is enabled (instead of disabled)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `56`
+    pub activate_group: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// A pickup trigger, which changes the value of some item counter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PickupTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `80`
+    pub item_id: u16,
+
+    /// The value added to the item counter
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `77`
+    pub count: i32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A collision trigger, which toggles some group when two collision blocks collide
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollisionTrigger {
+    /// The id of the first collision block
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `80`
+    pub block_a: u16,
+
+    /// The id of the second collision block
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `95`
+    pub block_b: u16,
+
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// Whether the target group is enabled (instead of disabled)
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `56`
+    pub activate_group: bool,
+
+    /// Whether the target group is toggled when the blocks stop colliding, instead of when they
+    /// start colliding
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `93`
+    pub trigger_on_exit: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum modelling what a [`TouchTrigger`] does to its target group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ToggleMode {
+    /// The target group is enabled if it is disabled, and disabled otherwise
+    #[default]
+    Toggle,
+    On,
+    Off,
+    Unknown(u8),
+}
+
+impl From<u8> for ToggleMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => ToggleMode::Toggle,
+            1 => ToggleMode::On,
+            2 => ToggleMode::Off,
+            unknown => ToggleMode::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ToggleMode> for u8 {
+    fn from(mode: ToggleMode) -> Self {
+        match mode {
+            ToggleMode::Toggle => 0,
+            ToggleMode::On => 1,
+            ToggleMode::Off => 2,
+            ToggleMode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// A touch trigger, which toggles some group when the player clicks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TouchTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// Whether the target group is toggled back once the player releases
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `81`
+    pub hold_mode: bool,
+
+    /// ## GD Internals:
+    /// This value is provided at index `82`
+    pub toggle_mode: ToggleMode,
+
+    /// Whether only clicks on one half of the screen are considered in dual mode
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `89`
+    pub dual_mode: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// A random trigger, which activates one of two groups at random
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RandomTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub group_1: u16,
+
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub group_2: u16,
+
+    /// The chance of [`RandomTrigger::group_1`] being activated, in percent
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub chance: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A single step of a [`SequenceTrigger`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceStep {
+    /// The group activated in this step
+    pub group: u16,
+
+    /// How often the sequence trigger has to be activated to advance past this step
+    pub count: u16,
+}
+
+/// Enum modelling what a [`SequenceTrigger`] does once it reaches the end of its sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SequenceMode {
+    /// The sequence trigger stops activating groups
+    #[default]
+    Stop,
+    /// The sequence starts over
+    Loop,
+    /// The last group of the sequence keeps being activated
+    Last,
+    Unknown(u8),
+}
+
+impl From<u8> for SequenceMode {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => SequenceMode::Stop,
+            1 => SequenceMode::Loop,
+            2 => SequenceMode::Last,
+            unknown => SequenceMode::Unknown(unknown),
+        }
+    }
+}
+
+impl From<SequenceMode> for u8 {
+    fn from(mode: SequenceMode) -> Self {
+        match mode {
+            SequenceMode::Stop => 0,
+            SequenceMode::Loop => 1,
+            SequenceMode::Last => 2,
+            SequenceMode::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// A sequence trigger, which activates the next group of its sequence every time it is activated
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SequenceTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `435`, as a `.`-separated list alternating between group
+    /// ids and counts
+    pub steps: Vec<SequenceStep>,
+
+    /// The minimum time between two activations advancing the sequence, in seconds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `436`
+    pub min_interval: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `437`
+    pub mode: SequenceMode,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum modelling the different kinds of counters that item edit and item compare triggers can
+/// operate on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ItemKind {
+    /// A regular item counter
+    #[default]
+    Item,
+    Timer,
+    Points,
+    /// The time since the level was started
+    MainTime,
+    Attempts,
+    Unknown(u8),
+}
+
+impl From<u8> for ItemKind {
+    fn from(value: u8) -> Self {
+        match value {
+            0 | 1 => ItemKind::Item,
+            2 => ItemKind::Timer,
+            3 => ItemKind::Points,
+            4 => ItemKind::MainTime,
+            5 => ItemKind::Attempts,
+            unknown => ItemKind::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ItemKind> for u8 {
+    fn from(kind: ItemKind) -> Self {
+        match kind {
+            ItemKind::Item => 1,
+            ItemKind::Timer => 2,
+            ItemKind::Points => 3,
+            ItemKind::MainTime => 4,
+            ItemKind::Attempts => 5,
+            ItemKind::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Reference to a counter used by item edit and item compare triggers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub struct ItemReference {
+    pub id: u16,
+    pub kind: ItemKind,
+}
+
+/// Enum modelling the arithmetic operations item edit and item compare triggers can perform
+///
+/// When used to combine two values, [`ItemOperator::Set`] means that no operation takes place
+/// (e.g. the second value is ignored).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ItemOperator {
+    #[default]
+    Set,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Unknown(u8),
+}
+
+impl From<u8> for ItemOperator {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => ItemOperator::Set,
+            1 => ItemOperator::Add,
+            2 => ItemOperator::Subtract,
+            3 => ItemOperator::Multiply,
+            4 => ItemOperator::Divide,
+            unknown => ItemOperator::Unknown(unknown),
+        }
+    }
+}
+
+impl From<ItemOperator> for u8 {
+    fn from(operator: ItemOperator) -> Self {
+        match operator {
+            ItemOperator::Set => 0,
+            ItemOperator::Add => 1,
+            ItemOperator::Subtract => 2,
+            ItemOperator::Multiply => 3,
+            ItemOperator::Divide => 4,
+            ItemOperator::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// Enum modelling how an [`ItemCompareTrigger`] compares its two values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Comparison {
+    #[default]
+    Equals,
+    Larger,
+    LargerOrEquals,
+    Smaller,
+    SmallerOrEquals,
+    NotEquals,
+    Unknown(u8),
+}
+
+impl From<u8> for Comparison {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Comparison::Equals,
+            1 => Comparison::Larger,
+            2 => Comparison::LargerOrEquals,
+            3 => Comparison::Smaller,
+            4 => Comparison::SmallerOrEquals,
+            5 => Comparison::NotEquals,
+            unknown => Comparison::Unknown(unknown),
+        }
+    }
+}
+
+impl From<Comparison> for u8 {
+    fn from(comparison: Comparison) -> Self {
+        match comparison {
+            Comparison::Equals => 0,
+            Comparison::Larger => 1,
+            Comparison::LargerOrEquals => 2,
+            Comparison::Smaller => 3,
+            Comparison::SmallerOrEquals => 4,
+            Comparison::NotEquals => 5,
+            Comparison::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// An item edit trigger, which assigns the result of some computation on other counters to a
+/// counter
+///
+/// The computation performed is `target <assign_operator> (item_1 <operator_1> item_2)
+/// <operator_2> modifier`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ItemEditTrigger {
+    /// ## GD Internals:
+    /// The id is provided at index `80`, the kind at index `476`
+    pub item_1: ItemReference,
+
+    /// ## GD Internals:
+    /// The id is provided at index `95`, the kind at index `477`
+    pub item_2: ItemReference,
+
+    /// The counter the result is assigned to
+    ///
+    /// ## GD Internals:
+    /// The id is provided at index `51`, the kind at index `478`
+    pub target: ItemReference,
+
+    /// ## GD Internals:
+    /// This value is provided at index `479`
+    pub modifier: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `480`
+    pub assign_operator: ItemOperator,
+
+    /// ## GD Internals:
+    /// This value is provided at index `481`
+    pub operator_1: ItemOperator,
+
+    /// ## GD Internals:
+    /// This value is provided at index `482`
+    pub operator_2: ItemOperator,
+
+    pub activation: TriggerActivation,
+}
+
+/// An item compare trigger, which activates one of two groups depending on the result of
+/// comparing two counters
+///
+/// The comparison performed is `(item_1 <operator_1> modifier_1) <comparison> (item_2
+/// <operator_2> modifier_2)`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ItemCompareTrigger {
+    /// ## GD Internals:
+    /// The id is provided at index `80`, the kind at index `476`
+    pub item_1: ItemReference,
+
+    /// ## GD Internals:
+    /// The id is provided at index `95`, the kind at index `477`
+    pub item_2: ItemReference,
+
+    /// ## GD Internals:
+    /// This value is provided at index `479`
+    pub modifier_1: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `483`
+    pub modifier_2: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `480`
+    pub operator_1: ItemOperator,
+
+    /// ## GD Internals:
+    /// This value is provided at index `481`
+    pub operator_2: ItemOperator,
+
+    /// ## GD Internals:
+    /// This value is provided at index `482`
+    pub comparison: Comparison,
+
+    /// The group activated if the comparison holds
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub true_group: u16,
+
+    /// The group activated if the comparison does not hold
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub false_group: u16,
+
+    pub activation: TriggerActivation,
+}
+
+/// A camera zoom trigger, which changes how far the camera is zoomed in
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraZoomTrigger {
+    /// The zoom factor, where `1.0` is the default zoom
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `371`
+    pub zoom: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `30`
+    pub easing: Easing,
+
+    /// ## GD Internals:
+    /// This value is provided at index `85`
+    pub easing_rate: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A camera static trigger, which locks the camera onto some group
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraStaticTrigger {
+    /// The id of the group the camera is locked onto
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub target_group: u16,
+
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `30`
+    pub easing: Easing,
+
+    /// ## GD Internals:
+    /// This value is provided at index `85`
+    pub easing_rate: f32,
+
+    /// Whether this trigger releases the camera from a previous static trigger instead
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `110`
+    pub exit_static: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// A camera offset trigger, which moves the camera relative to the player
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CameraOffsetTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `28`
+    pub offset_x: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `29`
+    pub offset_y: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `30`
+    pub easing: Easing,
+
+    /// ## GD Internals:
+    /// This value is provided at index `85`
+    pub easing_rate: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A camera mode trigger, which changes how the camera follows the player
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CameraModeTrigger {
+    /// Whether the camera moves freely with the player, instead of being bounded by the current
+    /// game mode's section
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `111`
+    pub free_mode: bool,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum modelling the edges of the screen a [`CameraEdgeTrigger`] can set
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum CameraEdge {
+    #[default]
+    None,
+    Left,
+    Right,
+    Up,
+    Down,
+    Unknown(u8),
+}
+
+impl From<u8> for CameraEdge {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => CameraEdge::None,
+            1 => CameraEdge::Left,
+            2 => CameraEdge::Right,
+            3 => CameraEdge::Up,
+            4 => CameraEdge::Down,
+            unknown => CameraEdge::Unknown(unknown),
+        }
+    }
+}
+
+impl From<CameraEdge> for u8 {
+    fn from(edge: CameraEdge) -> Self {
+        match edge {
+            CameraEdge::None => 0,
+            CameraEdge::Left => 1,
+            CameraEdge::Right => 2,
+            CameraEdge::Up => 3,
+            CameraEdge::Down => 4,
+            CameraEdge::Unknown(unknown) => unknown,
+        }
+    }
+}
+
+/// A camera edge trigger, which stops the camera from moving past some object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CameraEdgeTrigger {
+    /// The id of the group whose position determines the edge
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// ## GD Internals:
+    /// This value is provided at index `164`
+    pub edge: CameraEdge,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum modelling the different shader triggers. Which shader a trigger controls is determined by
+/// its object id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Shader {
+    /// Configures the shader layers (object id `2904`)
+    Setup,
+    ShockWave,
+    ShockLine,
+    Glitch,
+    Chromatic,
+    ChromaticGlitch,
+    Pixelate,
+    LensCircle,
+    RadialBlur,
+    MotionBlur,
+    Bulge,
+    Pinch,
+    GrayScale,
+    Sepia,
+    InvertColor,
+    Hue,
+    EditColor,
+    SplitScreen,
+}
+
+impl Shader {
+    /// Returns the shader controlled by triggers with the given object id, or [`None`] if that id
+    /// does not belong to a shader trigger
+    pub fn from_object_id(id: u16) -> Option<Shader> {
+        Some(match id {
+            ids::SHADER_SETUP_TRIGGER => Shader::Setup,
+            ids::SHOCK_WAVE_SHADER => Shader::ShockWave,
+            ids::SHOCK_LINE_SHADER => Shader::ShockLine,
+            ids::GLITCH_SHADER => Shader::Glitch,
+            ids::CHROMATIC_SHADER => Shader::Chromatic,
+            ids::CHROMATIC_GLITCH_SHADER => Shader::ChromaticGlitch,
+            ids::PIXELATE_SHADER => Shader::Pixelate,
+            ids::LENS_CIRCLE_SHADER => Shader::LensCircle,
+            ids::RADIAL_BLUR_SHADER => Shader::RadialBlur,
+            ids::MOTION_BLUR_SHADER => Shader::MotionBlur,
+            ids::BULGE_SHADER => Shader::Bulge,
+            ids::PINCH_SHADER => Shader::Pinch,
+            ids::GRAY_SCALE_SHADER => Shader::GrayScale,
+            ids::SEPIA_SHADER => Shader::Sepia,
+            ids::INVERT_COLOR_SHADER => Shader::InvertColor,
+            ids::HUE_SHADER => Shader::Hue,
+            ids::EDIT_COLOR_SHADER => Shader::EditColor,
+            ids::SPLIT_SCREEN_SHADER => Shader::SplitScreen,
+            _ => return None,
+        })
+    }
+
+    /// Returns the object id of triggers controlling this shader
+    pub fn object_id(self) -> u16 {
+        match self {
+            Shader::Setup => ids::SHADER_SETUP_TRIGGER,
+            Shader::ShockWave => ids::SHOCK_WAVE_SHADER,
+            Shader::ShockLine => ids::SHOCK_LINE_SHADER,
+            Shader::Glitch => ids::GLITCH_SHADER,
+            Shader::Chromatic => ids::CHROMATIC_SHADER,
+            Shader::ChromaticGlitch => ids::CHROMATIC_GLITCH_SHADER,
+            Shader::Pixelate => ids::PIXELATE_SHADER,
+            Shader::LensCircle => ids::LENS_CIRCLE_SHADER,
+            Shader::RadialBlur => ids::RADIAL_BLUR_SHADER,
+            Shader::MotionBlur => ids::MOTION_BLUR_SHADER,
+            Shader::Bulge => ids::BULGE_SHADER,
+            Shader::Pinch => ids::PINCH_SHADER,
+            Shader::GrayScale => ids::GRAY_SCALE_SHADER,
+            Shader::Sepia => ids::SEPIA_SHADER,
+            Shader::InvertColor => ids::INVERT_COLOR_SHADER,
+            Shader::Hue => ids::HUE_SHADER,
+            Shader::EditColor => ids::EDIT_COLOR_SHADER,
+            Shader::SplitScreen => ids::SPLIT_SCREEN_SHADER,
+        }
+    }
+}
+
+/// A shader trigger, which applies some post-processing effect to the screen
+///
+/// Most shaders have a large number of settings that are specific to them. These are not modelled
+/// and can be found in [`LevelObject::unknown_properties`](super::LevelObject::unknown_properties).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ShaderTrigger {
+    pub shader: Shader,
+
+    /// ## GD Internals:
+    /// This value is provided at index `10`
+    pub duration: f32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `30`
+    pub easing: Easing,
+
+    /// ## GD Internals:
+    /// This value is provided at index `85`
+    pub easing_rate: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A song trigger, which starts playing a song from the song library
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SongTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `392`
+    pub song_id: u32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `406`
+    pub volume: f32,
+
+    /// The change in playback speed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `404`
+    pub speed: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A SFX trigger, which plays a sound effect from the sound effect library
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SfxTrigger {
+    /// ## GD Internals:
+    /// This value is provided at index `392`
+    pub sfx_id: u32,
+
+    /// ## GD Internals:
+    /// This value is provided at index `406`
+    pub volume: f32,
+
+    /// The change in playback speed
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `404`
+    pub speed: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// Enum modelling the different area triggers. Which effect a trigger applies is determined by its
+/// object id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AreaEffect {
+    Move,
+    Rotate,
+    Scale,
+    Fade,
+    Tint,
+}
+
+impl AreaEffect {
+    /// Returns the effect applied by area triggers with the given object id, or [`None`] if that
+    /// id does not belong to an area trigger
+    pub fn from_object_id(id: u16) -> Option<AreaEffect> {
+        Some(match id {
+            ids::AREA_MOVE_TRIGGER => AreaEffect::Move,
+            ids::AREA_ROTATE_TRIGGER => AreaEffect::Rotate,
+            ids::AREA_SCALE_TRIGGER => AreaEffect::Scale,
+            ids::AREA_FADE_TRIGGER => AreaEffect::Fade,
+            ids::AREA_TINT_TRIGGER => AreaEffect::Tint,
+            _ => return None,
+        })
+    }
+
+    /// Returns the object id of area triggers applying this effect
+    pub fn object_id(self) -> u16 {
+        match self {
+            AreaEffect::Move => ids::AREA_MOVE_TRIGGER,
+            AreaEffect::Rotate => ids::AREA_ROTATE_TRIGGER,
+            AreaEffect::Scale => ids::AREA_SCALE_TRIGGER,
+            AreaEffect::Fade => ids::AREA_FADE_TRIGGER,
+            AreaEffect::Tint => ids::AREA_TINT_TRIGGER,
+        }
+    }
+}
+
+/// An area trigger, which applies some effect to all objects of some group depending on their
+/// distance to some center
+///
+/// The effect specific settings are not modelled and can be found in
+/// [`LevelObject::unknown_properties`](super::LevelObject::unknown_properties).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AreaTrigger {
+    pub effect: AreaEffect,
+
+    /// The id of the group the effect is applied to
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `51`
+    pub target_group: u16,
+
+    /// The id of the group around which the area is centered
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `71`
+    pub center_group: u16,
+
+    pub activation: TriggerActivation,
+}
+
+/// A time warp trigger, which changes the speed at which all triggers progress
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TimeWarpTrigger {
+    /// The factor by which time is sped up
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `120`
+    pub time_modifier: f32,
+
+    pub activation: TriggerActivation,
+}
+
+/// A gravity trigger, which changes the strength of the player's gravity
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct GravityTrigger {
+    /// The factor by which gravity is multiplied
+    ///
+    /// ## GD Internals:
+    /// This value is provided at index `148`
+    pub gravity: f32,
+
+    pub activation: TriggerActivation,
+}
@@ -0,0 +1,93 @@
+//! Module containing [`Selection`], a subset of the objects of a level that editing operations
+//! can be applied to
+
+use crate::model::level::{object::LevelObject, stats::BoundingBox, Objects};
+
+/// A subset of the objects of a level, given by their indices in [`Objects::objects`]
+///
+/// A selection is only meaningful for the [`Objects`] it was created from. Selections do not track
+/// changes to the level, so any operation adding or removing objects invalidates them.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Selection {
+    indices: Vec<usize>,
+}
+
+impl Selection {
+    /// Creates a selection from the given object indices. Duplicates are removed.
+    pub fn new(indices: impl IntoIterator<Item = usize>) -> Selection {
+        let mut indices: Vec<usize> = indices.into_iter().collect();
+
+        indices.sort_unstable();
+        indices.dedup();
+
+        Selection { indices }
+    }
+
+    /// The indices of the selected objects, in ascending order
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        self.indices.binary_search(&index).is_ok()
+    }
+
+    /// The selected objects of the given level, in the order they appear in the level
+    pub fn objects<'a>(&'a self, objects: &'a Objects) -> impl Iterator<Item = &'a LevelObject> + 'a {
+        self.indices.iter().map(move |&index| &objects.objects[index])
+    }
+
+    /// The smallest axis-aligned rectangle containing the positions of the selected objects, or
+    /// [`None`] if the selection is empty
+    pub fn bounding_box(&self, objects: &Objects) -> Option<BoundingBox> {
+        self.objects(objects).fold(None, |bounds, object| {
+            Some(match bounds {
+                None => BoundingBox {
+                    min_x: object.x,
+                    min_y: object.y,
+                    max_x: object.x,
+                    max_y: object.y,
+                },
+                Some(bounds) => BoundingBox {
+                    min_x: bounds.min_x.min(object.x),
+                    min_y: bounds.min_y.min(object.y),
+                    max_x: bounds.max_x.max(object.x),
+                    max_y: bounds.max_y.max(object.y),
+                },
+            })
+        })
+    }
+}
+
+impl Objects {
+    /// Selects all objects for which the given predicate holds
+    pub fn select(&self, mut predicate: impl FnMut(&LevelObject) -> bool) -> Selection {
+        Selection {
+            indices: self
+                .objects
+                .iter()
+                .enumerate()
+                .filter(|(_, object)| predicate(object))
+                .map(|(index, _)| index)
+                .collect(),
+        }
+    }
+
+    /// Selects all objects whose position lies inside the given area, including its edges
+    pub fn select_area(&self, area: &BoundingBox) -> Selection {
+        self.select(|object| area.min_x <= object.x && object.x <= area.max_x && area.min_y <= object.y && object.y <= area.max_y)
+    }
+
+    /// Selects all objects that are part of the given group
+    pub fn select_group(&self, group: u16) -> Selection {
+        self.select(|object| group != 0 && object.groups.contains(&group))
+    }
+}
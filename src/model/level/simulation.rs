@@ -0,0 +1,486 @@
+//! Module containing a simplified, deterministic simulation of the player's movement through a
+//! level
+//!
+//! The simulation is meant for sanity checking levels offline, e.g. verifying that an auto level
+//! can be completed or that a generated level does not contain impossible sections. It does not
+//! replicate the game's physics frame-perfectly:
+//! * All physics constants are approximations
+//! * The player's hitbox does not rotate, and solids (including slopes) are treated as their
+//!   bounding boxes
+//! * Only the first player is simulated in dual mode
+//! * Robots behave like cubes, spiders like balls and swing copters like ships that flip gravity
+//!   on click
+//! * Speed portals take effect at their x position, the same way they do in the [`Timeline`]
+//! * Dash, toggle and teleport orbs do not have their special effects
+
+use crate::model::level::{
+    gameplay::GameplayState,
+    hitbox::HitboxIndex,
+    object::{
+        game_mode::GameMode,
+        ids::HitboxType,
+        interactive::{DashOrb, Orb, Pad},
+        ObjectData,
+    },
+    stats::BoundingBox,
+    timeline::{units_per_second, Timeline},
+    Objects,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// The side length of the player's hitbox
+const PLAYER_SIZE: f32 = 30.0;
+
+/// Factor by which the player's hitbox and jumps shrink in mini mode
+const MINI_SCALE: f32 = 0.6;
+const MINI_JUMP_FACTOR: f32 = 0.8;
+
+// Vertical physics constants, in units per second and units per second squared respectively
+const CUBE_GRAVITY: f32 = 3200.0;
+const CUBE_JUMP: f32 = 645.0;
+const MAX_FALL_SPEED: f32 = 800.0;
+const SHIP_ACCELERATION: f32 = 1800.0;
+const SHIP_GRAVITY: f32 = 1500.0;
+const SHIP_MAX_SPEED: f32 = 480.0;
+const BALL_GRAVITY: f32 = 2500.0;
+const UFO_GRAVITY: f32 = 2300.0;
+const UFO_JUMP: f32 = 520.0;
+
+/// How far the player may sink into a solid's surface in a single tick (in addition to the
+/// distance they moved vertically) while still landing on it instead of crashing into its side
+const LANDING_TOLERANCE: f32 = 3.0;
+
+/// The distance from the ground above (or below) which the player is considered to have left the
+/// level
+const MAX_HEIGHT: f32 = 3000.0;
+
+/// A change in whether the player is holding down the jump button
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Input {
+    /// The x position at which the change happens
+    pub x: f32,
+
+    /// Whether the button is held down from this position onward
+    pub hold: bool,
+}
+
+impl Input {
+    /// Creates the two inputs for a single click, pressing the button at `x` and releasing it at
+    /// `x + length`
+    pub fn click(x: f32, length: f32) -> [Input; 2] {
+        [
+            Input { x, hold: true },
+            Input {
+                x: x + length,
+                hold: false,
+            },
+        ]
+    }
+}
+
+/// How a simulation ended
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    /// The player reached the end of the level
+    Completed,
+
+    /// The player died at the given position
+    Died {
+        x: f32,
+        y: f32,
+
+        /// The index (in [`Objects::objects`]) of the object the player crashed into, or
+        /// [`None`] if they crashed into the ground or left the level
+        object: Option<usize>,
+    },
+}
+
+/// The result of a simulation, see [`Simulator::run`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationResult {
+    pub outcome: Outcome,
+
+    /// The number of ticks simulated
+    pub ticks: u32,
+
+    /// The position of the player after each tick, if enabled via [`Simulator::record_path`]. Does
+    /// not include the tick the player died in.
+    pub path: Vec<(f32, f32)>,
+}
+
+impl SimulationResult {
+    pub fn survived(&self) -> bool {
+        self.outcome == Outcome::Completed
+    }
+}
+
+struct Player {
+    x: f32,
+    y: f32,
+
+    /// The vertical velocity of the player, positive when moving against gravity
+    rise: f32,
+    state: GameplayState,
+
+    /// Whether the player was standing on a surface at the end of the last tick
+    grounded: bool,
+    holding: bool,
+
+    /// Whether the player clicked and has not used that click yet (to jump, or activate an orb)
+    buffered: bool,
+}
+
+impl Player {
+    fn half_size(&self) -> f32 {
+        PLAYER_SIZE / 2.0 * if self.state.mini { MINI_SCALE } else { 1.0 }
+    }
+
+    /// The world space direction of "up" relative to gravity
+    fn up(&self) -> f32 {
+        if self.state.gravity_inverted {
+            -1.0
+        } else {
+            1.0
+        }
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        let half = self.half_size();
+
+        BoundingBox {
+            min_x: self.x - half,
+            min_y: self.y - half,
+            max_x: self.x + half,
+            max_y: self.y + half,
+        }
+    }
+
+    fn launch(&mut self, factor: f32) {
+        self.rise = CUBE_JUMP * factor * if self.state.mini { MINI_JUMP_FACTOR } else { 1.0 };
+    }
+
+    fn flip_gravity(&mut self) {
+        self.state.gravity_inverted = !self.state.gravity_inverted;
+        self.rise = 0.0;
+    }
+
+    /// Updates the player's vertical velocity according to their game mode and input
+    fn accelerate(&mut self, horizontal_speed: f32, dt: f32) {
+        let jump_factor = if self.state.mini { MINI_JUMP_FACTOR } else { 1.0 };
+
+        match self.state.game_mode {
+            GameMode::Ship | GameMode::Swing => {
+                if self.state.game_mode == GameMode::Swing && self.buffered {
+                    self.buffered = false;
+                    self.flip_gravity();
+                }
+
+                let acceleration = if self.holding && self.state.game_mode == GameMode::Ship {
+                    SHIP_ACCELERATION
+                } else {
+                    -SHIP_GRAVITY
+                };
+
+                self.rise = (self.rise + acceleration * dt).clamp(-SHIP_MAX_SPEED, SHIP_MAX_SPEED);
+            },
+            GameMode::Ball | GameMode::Spider => {
+                if self.grounded && self.buffered {
+                    self.buffered = false;
+                    self.flip_gravity();
+                }
+
+                self.rise = (self.rise - BALL_GRAVITY * dt).max(-MAX_FALL_SPEED);
+            },
+            GameMode::Ufo => {
+                if self.buffered {
+                    self.buffered = false;
+                    self.rise = UFO_JUMP * jump_factor;
+                } else {
+                    self.rise = (self.rise - UFO_GRAVITY * dt).max(-MAX_FALL_SPEED);
+                }
+            },
+            GameMode::Wave => {
+                let slope = if self.state.mini { 2.0 } else { 1.0 };
+
+                self.rise = if self.holding {
+                    horizontal_speed * slope
+                } else {
+                    -horizontal_speed * slope
+                };
+            },
+            GameMode::Cube | GameMode::Robot | GameMode::Unknown(_) => {
+                if self.grounded && (self.holding || self.buffered) {
+                    self.buffered = false;
+                    self.rise = CUBE_JUMP * jump_factor;
+                } else {
+                    self.rise = (self.rise - CUBE_GRAVITY * dt).max(-MAX_FALL_SPEED);
+                }
+            },
+        }
+    }
+}
+
+fn overlaps(a: &BoundingBox, b: &BoundingBox) -> bool {
+    a.min_x < b.max_x && b.min_x < a.max_x && a.min_y < b.max_y && b.min_y < a.max_y
+}
+
+/// Headless simulator for the player's movement through a level
+///
+/// ```
+/// # use dash_rs::model::level::{builder::LevelBuilder, object::LevelObject, simulation::Input};
+/// let level = LevelBuilder::new()
+///     // a single spike
+///     .object(LevelObject::new(8, 300.0, 15.0))
+///     .object(LevelObject::new(1764, 600.0, 15.0))
+///     .build();
+/// let simulator = level.simulator();
+///
+/// assert!(!simulator.run(&[]).survived());
+/// assert!(simulator.run(&Input::click(240.0, 10.0)).survived());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Simulator<'a> {
+    objects: &'a Objects,
+    hitboxes: HitboxIndex<'a>,
+    timeline: Timeline,
+    ticks_per_second: u32,
+    record_path: bool,
+}
+
+impl<'a> Simulator<'a> {
+    /// Creates a simulator for the given level, running at 240 ticks per second
+    pub fn new(objects: &'a Objects) -> Simulator<'a> {
+        Simulator {
+            objects,
+            hitboxes: objects.hitbox_index(),
+            timeline: objects.timeline(),
+            ticks_per_second: 240,
+            record_path: false,
+        }
+    }
+
+    /// Sets the number of ticks simulated per second of gameplay. Higher values are more accurate,
+    /// but slower.
+    pub fn ticks_per_second(mut self, ticks_per_second: u32) -> Self {
+        self.ticks_per_second = ticks_per_second.max(1);
+        self
+    }
+
+    /// Sets whether to record the player's position after each tick in
+    /// [`SimulationResult::path`]
+    pub fn record_path(mut self, record_path: bool) -> Self {
+        self.record_path = record_path;
+        self
+    }
+
+    /// Simulates the player playing through the level with the given inputs
+    ///
+    /// The player starts at position `(0, 15)` in the state described by the level's header and
+    /// is not holding the button. Inputs do not need to be sorted.
+    pub fn run(&self, inputs: &[Input]) -> SimulationResult {
+        let dt = 1.0 / self.ticks_per_second as f32;
+
+        let mut inputs = inputs.to_vec();
+        inputs.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+        let mut inputs = inputs.into_iter().peekable();
+        let mut used = HashSet::new();
+        let mut result = SimulationResult {
+            outcome: Outcome::Completed,
+            ticks: 0,
+            path: Vec::new(),
+        };
+        let mut player = Player {
+            x: 0.0,
+            y: 0.0,
+            rise: 0.0,
+            state: self.objects.starting_state(),
+            grounded: true,
+            holding: false,
+            buffered: false,
+        };
+
+        player.y = player.half_size();
+
+        while player.x < self.timeline.end_x() {
+            // A release ends a click the player did not use, but a press always counts as a click,
+            // even if the button is released again before the end of the tick
+            let mut pressed = false;
+
+            while let Some(input) = inputs.next_if(|input| input.x <= player.x) {
+                pressed |= input.hold && !player.holding;
+                player.holding = input.hold;
+            }
+
+            player.buffered = pressed || (player.holding && player.buffered);
+
+            let horizontal_speed = units_per_second(self.timeline.speed_at(player.x));
+            let previous_y = player.y;
+
+            player.accelerate(horizontal_speed, dt);
+            player.x += horizontal_speed * dt;
+            player.y += player.rise * player.up() * dt;
+            result.ticks += 1;
+
+            if let Err(object) = self.collide(&mut player, previous_y, &mut used) {
+                result.outcome = Outcome::Died {
+                    x: player.x,
+                    y: player.y,
+                    object,
+                };
+
+                break;
+            }
+
+            if self.record_path {
+                result.path.push((player.x, player.y));
+            }
+
+            if player.y.abs() > MAX_HEIGHT {
+                result.outcome = Outcome::Died {
+                    x: player.x,
+                    y: player.y,
+                    object: None,
+                };
+
+                break;
+            }
+        }
+
+        result
+    }
+
+    /// Resolves collisions between the player and the level's objects, returning the index of the
+    /// object that killed the player, if any
+    fn collide(&self, player: &mut Player, previous_y: f32, used: &mut HashSet<usize>) -> Result<(), Option<usize>> {
+        let half = player.half_size();
+        let moved = (player.y - previous_y).abs() + LANDING_TOLERANCE;
+
+        // the ground, extending infinitely downwards
+        let mut solids = vec![(
+            BoundingBox {
+                min_x: f32::NEG_INFINITY,
+                min_y: f32::NEG_INFINITY,
+                max_x: f32::INFINITY,
+                max_y: 0.0,
+            },
+            None,
+        )];
+
+        solids.extend(
+            self.hitboxes
+                .query(&player.bounds())
+                .into_iter()
+                .filter(|hitbox| hitbox.kind == HitboxType::Solid)
+                .map(|hitbox| (hitbox.geometry.bounding_box(), Some(hitbox.index))),
+        );
+
+        player.grounded = false;
+
+        for (solid, index) in solids {
+            if !overlaps(&player.bounds(), &solid) {
+                continue;
+            }
+
+            let falling = player.y <= previous_y;
+
+            // whether the player hit the solid's top (true) or bottom (false) surface
+            let top = if falling && solid.max_y - (player.y - half) <= moved {
+                player.y = solid.max_y + half;
+                true
+            } else if !falling && (player.y + half) - solid.min_y <= moved {
+                player.y = solid.min_y - half;
+                false
+            } else {
+                return Err(index);
+            };
+
+            if top == (player.up() > 0.0) {
+                player.grounded = true;
+                player.rise = player.rise.max(0.0);
+            } else {
+                player.rise = player.rise.min(0.0);
+            }
+        }
+
+        for hitbox in self.hitboxes.query(&player.bounds()) {
+            match hitbox.kind {
+                HitboxType::Hazard => return Err(Some(hitbox.index)),
+                HitboxType::Special if !used.contains(&hitbox.index) && self.activate(player, &hitbox.object.metadata) => {
+                    used.insert(hitbox.index);
+                },
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies the effect of the portal, pad or orb with the given data to the player,
+    /// returning whether it was used up
+    fn activate(&self, player: &mut Player, data: &ObjectData) -> bool {
+        match *data {
+            ObjectData::SpeedPortal { .. } => (),
+            ObjectData::TeleportPortal { offset_y } => {
+                player.y += offset_y;
+            },
+            ObjectData::GameModePortal { .. }
+            | ObjectData::SizePortal { .. }
+            | ObjectData::GravityPortal { .. }
+            | ObjectData::MirrorPortal { .. }
+            | ObjectData::DualPortal { .. } => {
+                let inverted = player.state.gravity_inverted;
+
+                player.state.apply(data);
+
+                if player.state.gravity_inverted != inverted {
+                    player.rise = -player.rise / 2.0;
+                }
+            },
+            ObjectData::Pad { pad, .. } => match pad {
+                Pad::Yellow => player.launch(1.4),
+                Pad::Pink => player.launch(0.9),
+                Pad::Red => player.launch(1.8),
+                Pad::Blue | Pad::Spider => player.flip_gravity(),
+            },
+            ObjectData::Orb { .. } | ObjectData::DashOrb { .. } if !player.buffered => return false,
+            ObjectData::Orb { orb, .. } => {
+                player.buffered = false;
+
+                match orb {
+                    Orb::Yellow => player.launch(1.0),
+                    Orb::Pink => player.launch(0.75),
+                    Orb::Red => player.launch(1.35),
+                    Orb::Blue | Orb::Spider => player.flip_gravity(),
+                    Orb::Green => {
+                        player.flip_gravity();
+                        player.launch(1.0)
+                    },
+                    Orb::Black => player.launch(-1.5),
+                    Orb::Toggle | Orb::Teleport => (),
+                }
+            },
+            ObjectData::DashOrb { orb, .. } => {
+                player.buffered = false;
+
+                if orb == DashOrb::Pink {
+                    player.flip_gravity();
+                }
+
+                player.launch(1.0)
+            },
+            // coins, ...
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+impl Objects {
+    /// Creates a [`Simulator`] for this level
+    pub fn simulator(&self) -> Simulator<'_> {
+        Simulator::new(self)
+    }
+}
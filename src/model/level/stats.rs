@@ -0,0 +1,152 @@
+//! Module containing [`LevelStats`], a summary of the contents of a level
+
+use crate::model::level::{
+    object::{interactive::Coin, ObjectCategory, ObjectData},
+    Objects,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The smallest axis-aligned rectangle containing the positions of all objects in a level
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub min_x: f32,
+    pub min_y: f32,
+    pub max_x: f32,
+    pub max_y: f32,
+}
+
+impl BoundingBox {
+    pub fn width(&self) -> f32 {
+        self.max_x - self.min_x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.max_y - self.min_y
+    }
+
+    pub fn center(&self) -> (f32, f32) {
+        ((self.min_x + self.max_x) / 2.0, (self.min_y + self.max_y) / 2.0)
+    }
+}
+
+/// Statistics about the contents of a level, see [`Objects::stats`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct LevelStats {
+    /// The total number of objects in the level
+    pub object_count: usize,
+
+    /// The number of objects with each object id
+    pub objects_by_id: BTreeMap<u16, usize>,
+
+    /// The number of objects in each [`ObjectCategory`]. Categories without any objects are absent.
+    pub objects_by_category: BTreeMap<ObjectCategory, usize>,
+
+    /// The number of distinct groups objects are placed in
+    pub distinct_groups: usize,
+
+    /// The number of distinct color channels used, either by objects or as the target of color
+    /// triggers
+    pub distinct_color_channels: usize,
+
+    /// The area spanned by the level's objects, see [`Objects::bounding_box`]
+    pub bounding_box: Option<BoundingBox>,
+
+    /// The time, in seconds, it takes the player to reach the end of the level, see
+    /// [`Timeline::length_in_seconds`](crate::model::level::timeline::Timeline::length_in_seconds)
+    pub length_in_seconds: f32,
+
+    /// The number of objects per second of gameplay
+    pub objects_per_second: f32,
+
+    /// The number of secret coins
+    pub secret_coins: usize,
+
+    /// The number of user coins
+    pub user_coins: usize,
+
+    /// The number of orbs, including dash orbs
+    pub orbs: usize,
+
+    /// The number of jump pads
+    pub pads: usize,
+
+    /// Whether the level contains a dual portal, or starts in dual mode
+    pub has_dual: bool,
+
+    /// Whether the level has two player mode enabled
+    pub two_player: bool,
+}
+
+impl Objects {
+    /// The area spanned by the positions of this level's objects, or [`None`] if the level is
+    /// empty
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.objects.iter().fold(None, |bounds, object| {
+            Some(match bounds {
+                None => BoundingBox {
+                    min_x: object.x,
+                    min_y: object.y,
+                    max_x: object.x,
+                    max_y: object.y,
+                },
+                Some(bounds) => BoundingBox {
+                    min_x: bounds.min_x.min(object.x),
+                    min_y: bounds.min_y.min(object.y),
+                    max_x: bounds.max_x.max(object.x),
+                    max_y: bounds.max_y.max(object.y),
+                },
+            })
+        })
+    }
+
+    /// Computes statistics about the objects in this level
+    pub fn stats(&self) -> LevelStats {
+        let mut stats = LevelStats {
+            object_count: self.objects.len(),
+            has_dual: self.meta.dual_start,
+            two_player: self.meta.two_player_controls,
+            ..LevelStats::default()
+        };
+
+        let mut groups = BTreeSet::new();
+        let mut color_channels = BTreeSet::new();
+
+        for object in &self.objects {
+            *stats.objects_by_id.entry(object.id).or_default() += 1;
+            *stats.objects_by_category.entry(object.category()).or_default() += 1;
+
+            groups.extend(object.groups.iter().copied().filter(|&group| group != 0));
+            color_channels.extend(
+                [object.main_color, object.detail_color]
+                    .iter()
+                    .copied()
+                    .filter(|&channel| channel != 0),
+            );
+
+            match object.metadata {
+                ObjectData::ColorTrigger(ref trigger) => {
+                    color_channels.insert(trigger.target_channel);
+                },
+                ObjectData::DualPortal { dual: true } => stats.has_dual = true,
+                ObjectData::Coin { coin: Coin::Secret } => stats.secret_coins += 1,
+                ObjectData::Coin { coin: Coin::User } => stats.user_coins += 1,
+                ObjectData::Orb { .. } | ObjectData::DashOrb { .. } => stats.orbs += 1,
+                ObjectData::Pad { .. } => stats.pads += 1,
+                _ => (),
+            }
+        }
+
+        stats.bounding_box = self.bounding_box();
+
+        stats.distinct_groups = groups.len();
+        stats.distinct_color_channels = color_channels.len();
+        stats.length_in_seconds = self.length_in_seconds();
+
+        if stats.length_in_seconds > 0.0 {
+            stats.objects_per_second = stats.object_count as f32 / stats.length_in_seconds;
+        }
+
+        stats
+    }
+}
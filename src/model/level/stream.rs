@@ -0,0 +1,128 @@
+//! Module containing [`ObjectStream`], which decodes a level's objects one at a time
+
+use crate::{
+    model::level::{metadata::LevelMetadata, object::LevelObject, LevelProcessError},
+    GJFormat,
+};
+use base64::{engine::general_purpose::URL_SAFE, read::DecoderReader};
+use flate2::read::{GzDecoder, ZlibDecoder};
+use std::{
+    io::{BufRead, BufReader, Cursor, Read},
+    iter::FusedIterator,
+};
+
+/// Iterator over the objects of a level that decodes and decompresses the level string
+/// incrementally
+///
+/// Unlike processing a level's [`Objects`](crate::model::level::Objects), this never holds the
+/// entire decompressed level string or all of the level's objects in memory at once. It is thus
+/// well suited for computing statistics about a level in a single pass, or for searching for some
+/// specific object and stopping once it is found. If the whole level is needed anyway, processing
+/// it in one go is faster.
+///
+/// The level's metadata section is decoded eagerly when constructing the stream.
+///
+/// If reading or decompressing the level string fails, the error is yielded once and the stream
+/// ends afterwards.
+pub struct ObjectStream<'a> {
+    reader: BufReader<Box<dyn Read + 'a>>,
+    buffer: Vec<u8>,
+    meta: LevelMetadata,
+    /// Whether the end of the level string was reached or reading it failed
+    done: bool,
+}
+
+impl<'a> ObjectStream<'a> {
+    /// Starts decoding the given base64 encoded and compressed level string, as found in
+    /// [`LevelData::level_data`](crate::model::level::LevelData::level_data)
+    pub fn new(encoded: &'a str) -> Result<ObjectStream<'a>, LevelProcessError> {
+        let mut decoder = DecoderReader::new(encoded.as_bytes(), &URL_SAFE);
+
+        // See the comment in `Objects::from_unprocessed` on why we need to detect the compression
+        // scheme ourselves. To do so, we peek at the first two decoded bytes and then put them back.
+        let mut magic = [0u8; 2];
+
+        decoder.read_exact(&mut magic)?;
+
+        let decoded = Cursor::new(magic).chain(decoder);
+        let decompressed: Box<dyn Read + 'a> = match magic {
+            [0x1f, 0x8b] => Box::new(GzDecoder::new(decoded)),
+            [0x78, _] => Box::new(ZlibDecoder::new(decoded)),
+            _ => return Err(LevelProcessError::UnknownCompression),
+        };
+
+        let mut stream = ObjectStream {
+            reader: BufReader::new(decompressed),
+            buffer: Vec::new(),
+            meta: LevelMetadata::default(),
+            done: false,
+        };
+
+        let meta = match stream.next_raw() {
+            Some(meta) => LevelMetadata::from_gj_str(meta?).map_err(|err| LevelProcessError::Deserialize(err.to_string()))?,
+            None => return Err(LevelProcessError::MissingMetadata),
+        };
+
+        stream.meta = meta;
+
+        Ok(stream)
+    }
+
+    /// The metadata section of the level being decoded
+    pub fn meta(&self) -> &LevelMetadata {
+        &self.meta
+    }
+
+    /// Consumes this stream, returning the level's metadata section
+    pub fn into_meta(self) -> LevelMetadata {
+        self.meta
+    }
+
+    /// Returns the string of the next object without deserializing it, or [`None`] if all objects
+    /// have been read
+    ///
+    /// The returned string borrows a buffer that is reused for every object, so this performs no
+    /// allocations once the buffer is large enough to hold the longest object string.
+    pub fn next_raw(&mut self) -> Option<Result<&str, LevelProcessError>> {
+        if self.done {
+            return None;
+        }
+
+        self.buffer.clear();
+
+        match self.reader.read_until(b';', &mut self.buffer) {
+            Ok(0) => {
+                self.done = true;
+
+                return None;
+            },
+            Ok(_) => (),
+            Err(err) => {
+                // The decompressor's state is unspecified after an error, so retrying would at
+                // best yield the same error again
+                self.done = true;
+
+                return Some(Err(err.into()));
+            },
+        }
+
+        if self.buffer.last() == Some(&b';') {
+            self.buffer.pop();
+        }
+
+        Some(std::str::from_utf8(&self.buffer).map_err(|err| LevelProcessError::Deserialize(err.to_string())))
+    }
+}
+
+impl<'a> Iterator for ObjectStream<'a> {
+    type Item = Result<LevelObject, LevelProcessError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(
+            self.next_raw()?
+                .and_then(|object| LevelObject::from_gj_str(object).map_err(|err| LevelProcessError::Deserialize(err.to_string()))),
+        )
+    }
+}
+
+impl<'a> FusedIterator for ObjectStream<'a> {}
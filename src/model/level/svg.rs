@@ -0,0 +1,280 @@
+//! Module for rendering a simplified preview of a level's layout as an SVG image
+//!
+//! Objects are drawn as plain shapes depending on their [`ObjectCategory`], so no game assets are
+//! required. The preview is intended for thumbnails and for quickly inspecting a level, not as an
+//! accurate rendition of what the level looks like in game.
+
+use crate::model::level::{
+    color::{ColorChannel, BACKGROUND_CHANNEL, OBJECT_CHANNEL},
+    object::{game_mode::GameMode, LevelObject, ObjectCategory},
+    Objects,
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Write},
+};
+
+/// The size of a single grid cell in the editor, in units
+const BLOCK_SIZE: f32 = 30.0;
+
+/// The color objects are drawn in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Color objects according to their [`ObjectCategory`]
+    #[default]
+    Category,
+
+    /// Color objects according to their main color channel, using the colors defined in the
+    /// level's header where available
+    ColorChannel,
+}
+
+/// Options controlling what [`Objects::write_svg`] renders
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgOptions {
+    pub color_mode: ColorMode,
+
+    /// The width of the resulting image in pixels. The height is chosen to preserve the level's
+    /// aspect ratio. If [`None`], one unit in the level corresponds to one pixel.
+    pub width: Option<f32>,
+
+    /// Whether to draw triggers, which are invisible during gameplay
+    pub triggers: bool,
+
+    /// Whether to draw a vertical line at the position of each portal
+    pub portal_overlay: bool,
+
+    /// Whether to mark the position of each start position
+    pub start_position_overlay: bool,
+
+    /// Whether to shade the background according to the game mode the player is in
+    pub game_mode_overlay: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        SvgOptions {
+            color_mode: ColorMode::Category,
+            width: None,
+            triggers: false,
+            portal_overlay: true,
+            start_position_overlay: true,
+            game_mode_overlay: false,
+        }
+    }
+}
+
+fn category_color(category: ObjectCategory) -> &'static str {
+    match category {
+        ObjectCategory::Decoration => "#6b7280",
+        ObjectCategory::Solid => "#e5e7eb",
+        ObjectCategory::Hazard => "#ef4444",
+        ObjectCategory::Trigger => "#a855f7",
+        ObjectCategory::Portal => "#22c55e",
+        ObjectCategory::Interactive => "#facc15",
+    }
+}
+
+fn game_mode_color(game_mode: GameMode) -> &'static str {
+    match game_mode {
+        GameMode::Cube => "#3b82f6",
+        GameMode::Ship => "#ec4899",
+        GameMode::Ball => "#ef4444",
+        GameMode::Ufo => "#f97316",
+        GameMode::Wave => "#06b6d4",
+        GameMode::Robot => "#e5e7eb",
+        GameMode::Spider => "#8b5cf6",
+        GameMode::Swing => "#eab308",
+        GameMode::Unknown(_) => "#6b7280",
+    }
+}
+
+/// Formats the color of a channel as a CSS hex color
+fn channel_color(channel: &ColorChannel) -> String {
+    format!("#{:02x}{:02x}{:02x}", channel.red, channel.green, channel.blue)
+}
+
+/// Picks an arbitrary, but stable, color for channels not defined in the level's header
+fn fallback_channel_color(channel: u16) -> String {
+    format!("hsl({}, 70%, 60%)", (channel as u32 * 137) % 360)
+}
+
+impl Objects {
+    /// Renders a preview of this level as an SVG image, see [`Objects::write_svg`]
+    pub fn to_svg(&self, options: &SvgOptions) -> String {
+        let mut svg = String::new();
+
+        self.write_svg(options, &mut svg).expect("writing to a String cannot fail");
+
+        svg
+    }
+
+    /// Renders a preview of this level as an SVG image, writing it to `out`
+    ///
+    /// Each object is drawn as a simple shape with its position, rotation, flip and scale applied:
+    /// solids and decoration as squares, hazards as triangles, orbs, pads and coins as circles,
+    /// portals as tall rectangles and triggers (if enabled) as small squares.
+    pub fn write_svg<W: Write>(&self, options: &SvgOptions, out: &mut W) -> fmt::Result {
+        let channels: HashMap<u16, &ColorChannel> = self.meta.color_channels.iter().map(|channel| (channel.id, channel)).collect();
+
+        let bounds = self.bounding_box();
+        let (min_x, min_y, max_x, max_y) = match bounds {
+            Some(bounds) => (
+                bounds.min_x - BLOCK_SIZE,
+                bounds.min_y - BLOCK_SIZE,
+                bounds.max_x + BLOCK_SIZE,
+                bounds.max_y + BLOCK_SIZE,
+            ),
+            None => (0.0, 0.0, BLOCK_SIZE, BLOCK_SIZE),
+        };
+        let (width, height) = (max_x - min_x, max_y - min_y);
+        let (image_width, image_height) = match options.width {
+            Some(image_width) => (image_width, image_width * height / width),
+            None => (width, height),
+        };
+
+        let background = match channels.get(&BACKGROUND_CHANNEL) {
+            Some(channel) => channel_color(channel),
+            None => "#1f2937".to_string(),
+        };
+
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            image_width, image_height, min_x, -max_y, width, height
+        )?;
+        writeln!(
+            out,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+            min_x, -max_y, width, height, background
+        )?;
+
+        // The game's y axis points upwards, while SVG's points downwards
+        writeln!(out, r#"<g transform="scale(1,-1)">"#)?;
+
+        if options.game_mode_overlay {
+            let sections = self.gameplay_sections();
+
+            for (index, section) in sections.iter().enumerate() {
+                let start = section.start_x.max(min_x);
+                let end = sections.get(index + 1).map(|next| next.start_x).unwrap_or(max_x);
+
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" fill-opacity="0.2"/>"#,
+                    start,
+                    min_y,
+                    (end - start).max(0.0),
+                    height,
+                    game_mode_color(section.state.game_mode)
+                )?;
+            }
+        }
+
+        for object in &self.objects {
+            let category = object.category();
+
+            if category == ObjectCategory::Trigger && !options.triggers {
+                continue;
+            }
+
+            let fill = match options.color_mode {
+                ColorMode::Category => category_color(category).to_string(),
+                ColorMode::ColorChannel => {
+                    let channel = if object.main_color == 0 {
+                        OBJECT_CHANNEL
+                    } else {
+                        object.main_color
+                    };
+
+                    match channels.get(&channel) {
+                        Some(channel) => channel_color(channel),
+                        None => fallback_channel_color(channel),
+                    }
+                },
+            };
+
+            write_object(out, object, category, &fill)?;
+        }
+
+        if options.portal_overlay {
+            for object in self.objects.iter().filter(|object| object.category() == ObjectCategory::Portal) {
+                writeln!(
+                    out,
+                    r#"<line x1="{0}" y1="{1}" x2="{0}" y2="{2}" stroke="{3}" stroke-width="2" stroke-dasharray="6,6" stroke-opacity="0.6"/>"#,
+                    object.x,
+                    min_y,
+                    max_y,
+                    category_color(ObjectCategory::Portal)
+                )?;
+            }
+        }
+
+        if options.start_position_overlay {
+            for (object, start_pos) in self.start_positions() {
+                writeln!(
+                    out,
+                    r#"<path d="M {} {} l 0 -30 l -20 10 z" fill="{}" fill-opacity="{}"/>"#,
+                    object.x,
+                    object.y + BLOCK_SIZE,
+                    game_mode_color(start_pos.game_mode),
+                    if start_pos.disabled { 0.4 } else { 1.0 }
+                )?;
+            }
+        }
+
+        writeln!(out, "</g>")?;
+        writeln!(out, "</svg>")
+    }
+}
+
+fn write_object<W: Write>(out: &mut W, object: &LevelObject, category: ObjectCategory, fill: &str) -> fmt::Result {
+    let flip_x = if object.flipped_x { -1.0 } else { 1.0 };
+    let flip_y = if object.flipped_y { -1.0 } else { 1.0 };
+
+    // Positive rotations are clockwise in game, but counter-clockwise once the y axis is flipped
+    write!(
+        out,
+        r#"<g transform="translate({},{}) rotate({}) scale({},{})">"#,
+        object.x,
+        object.y,
+        -object.rotation,
+        flip_x * object.scale * object.scale_x,
+        flip_y * object.scale * object.scale_y
+    )?;
+
+    let half = BLOCK_SIZE / 2.0;
+
+    match category {
+        ObjectCategory::Hazard => write!(out, r#"<path d="M {0} {0} L {1} {0} L 0 {1} z" fill="{2}"/>"#, -half, half, fill)?,
+        ObjectCategory::Interactive => write!(out, r#"<circle r="{}" fill="{}"/>"#, half * 0.8, fill)?,
+        ObjectCategory::Portal => write!(
+            out,
+            r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" fill-opacity="0.8"/>"#,
+            -half * 0.8,
+            -half * 3.0,
+            BLOCK_SIZE * 0.8,
+            BLOCK_SIZE * 3.0,
+            fill
+        )?,
+        ObjectCategory::Trigger => write!(
+            out,
+            r#"<rect x="{0}" y="{0}" width="{1}" height="{1}" fill="{2}"/>"#,
+            -half / 2.0,
+            half,
+            fill
+        )?,
+        ObjectCategory::Solid => write!(
+            out,
+            r#"<rect x="{0}" y="{0}" width="{1}" height="{1}" fill="{2}"/>"#,
+            -half, BLOCK_SIZE, fill
+        )?,
+        ObjectCategory::Decoration => write!(
+            out,
+            r#"<rect x="{0}" y="{0}" width="{1}" height="{1}" fill="{2}" fill-opacity="0.5"/>"#,
+            -half, BLOCK_SIZE, fill
+        )?,
+    }
+
+    writeln!(out, "</g>")
+}
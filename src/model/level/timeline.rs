@@ -0,0 +1,226 @@
+//! Module containing the [`Timeline`] of a level, which converts between positions in the level
+//! and the time at which the player reaches them
+
+use crate::model::level::{
+    object::{speed::Speed, ObjectData},
+    LevelLength, Objects,
+};
+use serde::{Deserialize, Serialize};
+
+/// A point in a level at which the player's speed changes
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpeedChange {
+    /// The x position of the speed portal causing the change
+    pub x: f32,
+
+    /// The time, in seconds since the level started, at which the player reaches this change
+    pub seconds: f32,
+
+    /// The speed of the player after this change
+    pub speed: Speed,
+}
+
+/// An orb the player has to click to use, see [`Objects::click_points`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClickPoint {
+    /// The index of the orb in [`Objects::objects`]
+    pub index: usize,
+
+    /// The x position of the orb
+    pub x: f32,
+
+    /// The time, in seconds since the level started, at which the player reaches the orb
+    pub seconds: f32,
+}
+
+/// Mapping between the x position in a level and the time, in seconds, the player needs to reach
+/// it, see [`Objects::timeline`]
+///
+/// The player is assumed to move at a constant horizontal speed, only changing when passing
+/// through a speed portal. This does not hold for platformer levels, where the player moves freely.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline {
+    /// All speed changes in the level, sorted by x position. The first entry always describes the
+    /// starting speed at position `0`.
+    changes: Vec<SpeedChange>,
+
+    end_x: f32,
+    song_offset: f32,
+    platformer: bool,
+}
+
+/// Returns the horizontal speed of the player in units per second, treating unknown speeds as
+/// normal speed
+pub(crate) fn units_per_second(speed: Speed) -> f32 {
+    match speed {
+        Speed::Unknown(_) => Speed::Normal.into(),
+        speed => speed.into(),
+    }
+}
+
+impl Timeline {
+    pub fn new(objects: &Objects) -> Timeline {
+        let mut portals = Vec::new();
+        let mut end_x: f32 = 0.0;
+
+        for object in &objects.objects {
+            if let ObjectData::SpeedPortal { speed, .. } = object.metadata {
+                portals.push((object.x, speed));
+            }
+
+            end_x = end_x.max(object.x);
+        }
+
+        // stable sort, so that of multiple portals at the same position the last one in the level wins
+        portals.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
+
+        let mut changes = vec![SpeedChange {
+            x: 0.0,
+            seconds: 0.0,
+            speed: objects.meta.starting_speed,
+        }];
+
+        for (x, speed) in portals {
+            let last = changes[changes.len() - 1];
+
+            // Portals placed before the start of the level simply change the starting speed
+            let x = x.max(0.0);
+            let seconds = last.seconds + (x - last.x) / units_per_second(last.speed);
+
+            if x == last.x {
+                changes.pop();
+            }
+
+            changes.push(SpeedChange { x, seconds, speed });
+        }
+
+        Timeline {
+            changes,
+            end_x,
+            song_offset: objects.meta.song_offset as f32,
+            platformer: objects.meta.platformer,
+        }
+    }
+
+    /// All points at which the player's speed changes, sorted by x position. The first entry is
+    /// always at position `0` and describes the speed the player starts with.
+    pub fn speed_changes(&self) -> &[SpeedChange] {
+        &self.changes
+    }
+
+    /// Returns the last speed change at or before the given x position
+    fn change_at(&self, x: f32) -> &SpeedChange {
+        let index = self.changes.partition_point(|change| change.x <= x);
+
+        &self.changes[index.saturating_sub(1)]
+    }
+
+    /// Returns the speed the player has at the given x position
+    pub fn speed_at(&self, x: f32) -> Speed {
+        self.change_at(x).speed
+    }
+
+    /// Returns the time, in seconds since the start of the level, at which the player reaches the
+    /// given x position
+    pub fn seconds_at(&self, x: f32) -> f32 {
+        let change = self.change_at(x);
+
+        change.seconds + (x - change.x) / units_per_second(change.speed)
+    }
+
+    /// Returns the x position the player is at the given number of seconds after the level started
+    pub fn x_at(&self, seconds: f32) -> f32 {
+        let index = self.changes.partition_point(|change| change.seconds <= seconds);
+        let change = &self.changes[index.saturating_sub(1)];
+
+        change.x + (seconds - change.seconds) * units_per_second(change.speed)
+    }
+
+    /// Returns the point in the level's song, in seconds, that is playing when the player reaches
+    /// the given x position. This differs from [`Timeline::seconds_at`] by the level's song offset.
+    pub fn song_position_at(&self, x: f32) -> f32 {
+        self.seconds_at(x) + self.song_offset
+    }
+
+    /// The x position of the level's end, which is the position of its right-most object
+    pub fn end_x(&self) -> f32 {
+        self.end_x
+    }
+
+    /// The time, in seconds, it takes the player to reach the end of the level
+    pub fn length_in_seconds(&self) -> f32 {
+        self.seconds_at(self.end_x)
+    }
+
+    /// Computes the length class of the level the way the game does when uploading it
+    ///
+    /// This can be compared against the [`LevelLength`] reported by the servers to detect levels
+    /// whose length was misclassified.
+    pub fn level_length(&self) -> LevelLength {
+        if self.platformer {
+            return LevelLength::Platformer;
+        }
+
+        match self.length_in_seconds() {
+            seconds if seconds < 10.0 => LevelLength::Tiny,
+            seconds if seconds < 30.0 => LevelLength::Short,
+            seconds if seconds < 60.0 => LevelLength::Medium,
+            seconds if seconds < 120.0 => LevelLength::Long,
+            _ => LevelLength::ExtraLong,
+        }
+    }
+}
+
+impl Objects {
+    /// Builds the [`Timeline`] of this level
+    pub fn timeline(&self) -> Timeline {
+        Timeline::new(self)
+    }
+
+    /// Lists all orbs (including dash orbs) in this level, together with the time at which the
+    /// player reaches them, sorted by x position
+    ///
+    /// Jump pads are not included, as they do not require any input.
+    pub fn click_points(&self) -> Vec<ClickPoint> {
+        let timeline = self.timeline();
+        let mut clicks: Vec<ClickPoint> = self
+            .objects
+            .iter()
+            .enumerate()
+            .filter(|(_, object)| matches!(object.metadata, ObjectData::Orb { .. } | ObjectData::DashOrb { .. }))
+            .map(|(index, object)| ClickPoint {
+                index,
+                x: object.x,
+                seconds: timeline.seconds_at(object.x),
+            })
+            .collect();
+
+        clicks.sort_by(|a, b| a.x.total_cmp(&b.x));
+        clicks
+    }
+
+    /// Computes the highest number of [click points](Objects::click_points) per second within any
+    /// interval of the given length, in seconds
+    ///
+    /// Orbs at the same position count as separate clicks. Returns `0.0` if the window is not
+    /// positive (or NaN).
+    pub fn peak_clicks_per_second(&self, window: f32) -> f32 {
+        if window.is_nan() || window <= 0.0 {
+            return 0.0;
+        }
+
+        let clicks = self.click_points();
+        let mut start = 0;
+        let mut peak = 0;
+
+        for (end, click) in clicks.iter().enumerate() {
+            while click.seconds - clicks[start].seconds > window {
+                start += 1;
+            }
+
+            peak = peak.max(end - start + 1);
+        }
+
+        peak as f32 / window
+    }
+}
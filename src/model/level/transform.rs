@@ -0,0 +1,183 @@
+//! Module containing geometric transformations of objects, applied either to single objects or to
+//! a [`Selection`] of a level's objects
+//!
+//! Besides an object's position, the transformations keep its rotation, flip and scale consistent,
+//! and adjust the offsets of triggers and portals that move things relative to themselves, so that
+//! for example a mirrored move trigger moves its group in the mirrored direction.
+//!
+//! As in the game, rotations are given in degrees and are clockwise. The rotation of transformed
+//! objects is always in the range `[0, 360)`.
+
+use crate::model::level::{
+    object::{LevelObject, ObjectData},
+    selection::Selection,
+    Objects,
+};
+
+/// The size of a cell of the editor grid, in units
+pub const GRID_SIZE: f32 = 30.0;
+
+/// Computes the sine and cosine of the given angle, in degrees, counter-clockwise. Multiples of 90°
+/// are handled exactly, to avoid floating point noise in the common case of quarter turns.
+fn sin_cos(degrees: f32) -> (f32, f32) {
+    const QUARTER_TURNS: [(f32, f32); 4] = [(0.0, 1.0), (1.0, 0.0), (0.0, -1.0), (-1.0, 0.0)];
+
+    let quarter_turns = degrees.rem_euclid(360.0) / 90.0;
+
+    if quarter_turns.fract() == 0.0 {
+        QUARTER_TURNS[quarter_turns as usize % 4]
+    } else {
+        degrees.to_radians().sin_cos()
+    }
+}
+
+/// Rotates the given vector clockwise by the given angle, in degrees
+fn rotate_vector((x, y): (f32, f32), degrees: f32) -> (f32, f32) {
+    let (sin, cos) = sin_cos(-degrees);
+
+    (x * cos - y * sin, x * sin + y * cos)
+}
+
+impl LevelObject {
+    pub fn translate(&mut self, offset_x: f32, offset_y: f32) {
+        self.x += offset_x;
+        self.y += offset_y;
+    }
+
+    /// Rotates this object clockwise by the given angle, in degrees, around the given point
+    ///
+    /// The offsets of move and camera offset triggers are rotated as well. The resulting rotation
+    /// of the object is in the range `[0, 360)`.
+    pub fn rotate_around(&mut self, degrees: f32, (pivot_x, pivot_y): (f32, f32)) {
+        let (x, y) = rotate_vector((self.x - pivot_x, self.y - pivot_y), degrees);
+
+        self.x = pivot_x + x;
+        self.y = pivot_y + y;
+        self.rotation = (self.rotation + degrees).rem_euclid(360.0);
+
+        let offset = match &mut self.metadata {
+            ObjectData::MoveTrigger(trigger) => Some((&mut trigger.offset_x, &mut trigger.offset_y)),
+            ObjectData::CameraOffsetTrigger(trigger) => Some((&mut trigger.offset_x, &mut trigger.offset_y)),
+            _ => None,
+        };
+
+        if let Some((offset_x, offset_y)) = offset {
+            let (x, y) = rotate_vector((*offset_x, *offset_y), degrees);
+
+            *offset_x = x;
+            *offset_y = y;
+        }
+    }
+
+    /// Scales this object by the given factor, moving it away from or towards the given point
+    ///
+    /// The offsets of move triggers and teleport portals are scaled as well.
+    pub fn scale_around(&mut self, factor: f32, (pivot_x, pivot_y): (f32, f32)) {
+        self.x = pivot_x + (self.x - pivot_x) * factor;
+        self.y = pivot_y + (self.y - pivot_y) * factor;
+        self.scale *= factor;
+
+        match &mut self.metadata {
+            ObjectData::MoveTrigger(trigger) => {
+                trigger.offset_x *= factor;
+                trigger.offset_y *= factor;
+            },
+            ObjectData::TeleportPortal { offset_y } => *offset_y *= factor,
+            _ => (),
+        }
+    }
+
+    /// Mirrors this object along the vertical line at the given x position
+    ///
+    /// Horizontal move and camera offsets are mirrored as well, and rotate triggers rotate in the
+    /// opposite direction.
+    pub fn mirror_horizontally(&mut self, axis_x: f32) {
+        self.x = 2.0 * axis_x - self.x;
+        self.flipped_x = !self.flipped_x;
+        self.rotation = (-self.rotation).rem_euclid(360.0);
+
+        match &mut self.metadata {
+            ObjectData::MoveTrigger(trigger) => trigger.offset_x = -trigger.offset_x,
+            ObjectData::CameraOffsetTrigger(trigger) => trigger.offset_x = -trigger.offset_x,
+            ObjectData::RotateTrigger(trigger) => {
+                trigger.degrees = -trigger.degrees;
+                trigger.full_rotations = -trigger.full_rotations;
+            },
+            _ => (),
+        }
+    }
+
+    /// Mirrors this object along the horizontal line at the given y position
+    ///
+    /// Vertical move, camera and teleport offsets are mirrored as well, and rotate triggers rotate
+    /// in the opposite direction.
+    pub fn mirror_vertically(&mut self, axis_y: f32) {
+        self.y = 2.0 * axis_y - self.y;
+        self.flipped_y = !self.flipped_y;
+        self.rotation = (-self.rotation).rem_euclid(360.0);
+
+        match &mut self.metadata {
+            ObjectData::MoveTrigger(trigger) => trigger.offset_y = -trigger.offset_y,
+            ObjectData::CameraOffsetTrigger(trigger) => trigger.offset_y = -trigger.offset_y,
+            ObjectData::TeleportPortal { offset_y } => *offset_y = -*offset_y,
+            ObjectData::RotateTrigger(trigger) => {
+                trigger.degrees = -trigger.degrees;
+                trigger.full_rotations = -trigger.full_rotations;
+            },
+            _ => (),
+        }
+    }
+
+    /// Moves this object to the center of the nearest cell of the editor grid
+    pub fn align_to_grid(&mut self) {
+        let half = GRID_SIZE / 2.0;
+
+        self.x = ((self.x - half) / GRID_SIZE).round() * GRID_SIZE + half;
+        self.y = ((self.y - half) / GRID_SIZE).round() * GRID_SIZE + half;
+    }
+}
+
+impl Objects {
+    fn transform(&mut self, selection: &Selection, mut f: impl FnMut(&mut LevelObject)) {
+        for &index in selection.indices() {
+            f(&mut self.objects[index])
+        }
+    }
+
+    /// Moves the selected objects by the given offset
+    pub fn translate(&mut self, selection: &Selection, offset_x: f32, offset_y: f32) {
+        self.transform(selection, |object| object.translate(offset_x, offset_y))
+    }
+
+    /// Rotates the selected objects clockwise by the given angle, in degrees, around the given
+    /// point, see [`LevelObject::rotate_around`]
+    ///
+    /// To rotate a selection around its center, use the center of
+    /// [`Selection::bounding_box`] as pivot.
+    pub fn rotate(&mut self, selection: &Selection, degrees: f32, pivot: (f32, f32)) {
+        self.transform(selection, |object| object.rotate_around(degrees, pivot))
+    }
+
+    /// Scales the selected objects by the given factor around the given point, see
+    /// [`LevelObject::scale_around`]
+    pub fn scale(&mut self, selection: &Selection, factor: f32, pivot: (f32, f32)) {
+        self.transform(selection, |object| object.scale_around(factor, pivot))
+    }
+
+    /// Mirrors the selected objects along the vertical line at the given x position, see
+    /// [`LevelObject::mirror_horizontally`]
+    pub fn mirror_horizontally(&mut self, selection: &Selection, axis_x: f32) {
+        self.transform(selection, |object| object.mirror_horizontally(axis_x))
+    }
+
+    /// Mirrors the selected objects along the horizontal line at the given y position, see
+    /// [`LevelObject::mirror_vertically`]
+    pub fn mirror_vertically(&mut self, selection: &Selection, axis_y: f32) {
+        self.transform(selection, |object| object.mirror_vertically(axis_y))
+    }
+
+    /// Moves each selected object to the center of the nearest cell of the editor grid
+    pub fn align_to_grid(&mut self, selection: &Selection) {
+        self.transform(selection, LevelObject::align_to_grid)
+    }
+}
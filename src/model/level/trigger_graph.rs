@@ -0,0 +1,227 @@
+//! Module containing the dependency graph between the triggers of a level
+
+use crate::model::level::{
+    object::{
+        trigger::{ItemKind, ItemReference, PulseTarget, ToggleMode},
+        ObjectData,
+    },
+    Objects,
+};
+use std::collections::{BTreeSet, HashMap};
+
+/// A single trigger inside a [`TriggerGraph`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TriggerNode {
+    /// The index of the trigger in [`Objects::objects`]
+    pub index: usize,
+
+    /// The groups the trigger is part of. If the trigger is spawn triggered, activating any of
+    /// these groups activates the trigger.
+    pub groups: Vec<u16>,
+
+    /// Whether the trigger is only activated through its groups, instead of by the player
+    pub spawn_triggered: bool,
+
+    /// The groups whose spawn triggered triggers this trigger (potentially) activates
+    pub activates: Vec<u16>,
+
+    /// The groups this trigger modifies without activating them, e.g. by moving or disabling them
+    pub targets: Vec<u16>,
+
+    /// The counters whose value this trigger reads
+    pub reads_items: Vec<ItemReference>,
+
+    /// The counters whose value this trigger changes
+    pub writes_items: Vec<ItemReference>,
+}
+
+/// The dependency graph between the triggers of a level, see [`Objects::trigger_graph`]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TriggerGraph {
+    /// All triggers of the level, in the order they appear in the level
+    pub nodes: Vec<TriggerNode>,
+}
+
+impl TriggerGraph {
+    /// Returns all triggers that (potentially) activate the given group
+    pub fn activators(&self, group: u16) -> impl Iterator<Item = &TriggerNode> {
+        self.nodes.iter().filter(move |node| node.activates.contains(&group))
+    }
+
+    /// Returns all spawn triggered triggers in the given group, e.g. those triggers activated when
+    /// the group gets activated
+    pub fn members(&self, group: u16) -> impl Iterator<Item = &TriggerNode> {
+        self.nodes
+            .iter()
+            .filter(move |node| node.spawn_triggered && node.groups.contains(&group))
+    }
+
+    /// Returns all triggers that read the given counter
+    pub fn readers(&self, item: ItemReference) -> impl Iterator<Item = &TriggerNode> {
+        self.nodes.iter().filter(move |node| node.reads_items.contains(&item))
+    }
+
+    /// Returns all triggers that change the given counter
+    pub fn writers(&self, item: ItemReference) -> impl Iterator<Item = &TriggerNode> {
+        self.nodes.iter().filter(move |node| node.writes_items.contains(&item))
+    }
+
+    /// Computes which triggers can possibly be activated during gameplay, starting from the
+    /// triggers activated directly by the player. The returned vector is indexed like
+    /// [`TriggerGraph::nodes`].
+    pub fn reachable(&self) -> Vec<bool> {
+        let mut members: HashMap<u16, Vec<usize>> = HashMap::new();
+
+        for (node_idx, node) in self.nodes.iter().enumerate().filter(|(_, node)| node.spawn_triggered) {
+            for &group in &node.groups {
+                members.entry(group).or_default().push(node_idx);
+            }
+        }
+
+        let mut reachable = vec![false; self.nodes.len()];
+        let mut stack = Vec::new();
+
+        for (node_idx, node) in self.nodes.iter().enumerate() {
+            if !node.spawn_triggered {
+                reachable[node_idx] = true;
+                stack.push(node_idx);
+            }
+        }
+
+        while let Some(node_idx) = stack.pop() {
+            for group in &self.nodes[node_idx].activates {
+                for &member in members.get(group).into_iter().flatten() {
+                    if !reachable[member] {
+                        reachable[member] = true;
+                        stack.push(member);
+                    }
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Returns the indices (into [`Objects::objects`]) of all spawn triggered triggers that can
+    /// never be activated, as no trigger reachable during gameplay activates any of their groups
+    pub fn dead_triggers(&self) -> Vec<usize> {
+        self.reachable()
+            .into_iter()
+            .zip(&self.nodes)
+            .filter(|(reachable, _)| !reachable)
+            .map(|(_, node)| node.index)
+            .collect()
+    }
+
+    /// Returns all groups that some trigger wants to activate, but which are never activated
+    /// during gameplay, as all triggers activating them are dead themselves
+    pub fn unreachable_groups(&self) -> BTreeSet<u16> {
+        let reachable = self.reachable();
+
+        let mut activated = BTreeSet::new();
+        let mut unreachable = BTreeSet::new();
+
+        for (node, reachable) in self.nodes.iter().zip(reachable) {
+            if reachable {
+                activated.extend(node.activates.iter().copied());
+            } else {
+                unreachable.extend(node.activates.iter().copied());
+            }
+        }
+
+        &unreachable - &activated
+    }
+}
+
+fn item(id: u16) -> ItemReference {
+    ItemReference { id, kind: ItemKind::Item }
+}
+
+impl Objects {
+    /// Builds the dependency graph between the triggers in this level
+    ///
+    /// Note that group id `0` is treated as "no group" and never appears in the graph
+    pub fn trigger_graph(&self) -> TriggerGraph {
+        let mut nodes = Vec::new();
+
+        for (index, object) in self.objects.iter().enumerate() {
+            let activation = match object.metadata.activation() {
+                Some(activation) => activation,
+                None => continue,
+            };
+
+            let mut node = TriggerNode {
+                index,
+                groups: object.groups.clone(),
+                spawn_triggered: activation.spawn_triggered,
+                ..TriggerNode::default()
+            };
+
+            match &object.metadata {
+                ObjectData::MoveTrigger(trigger) => {
+                    node.targets.push(trigger.target_group);
+                    if trigger.use_target {
+                        node.targets.push(trigger.target_position_group);
+                    }
+                },
+                ObjectData::RotateTrigger(trigger) => node.targets.extend([trigger.target_group, trigger.center_group]),
+                ObjectData::PulseTrigger(trigger) => {
+                    if let PulseTarget::Group(group) = trigger.target {
+                        node.targets.push(group)
+                    }
+                },
+                ObjectData::AlphaTrigger(trigger) => node.targets.push(trigger.target_group),
+                ObjectData::FollowTrigger(trigger) => node.targets.extend([trigger.target_group, trigger.follow_group]),
+                ObjectData::SpawnTrigger(trigger) => node.activates.push(trigger.target_group),
+                ObjectData::ToggleTrigger(trigger) if trigger.activate_group => node.activates.push(trigger.target_group),
+                ObjectData::ToggleTrigger(trigger) => node.targets.push(trigger.target_group),
+                ObjectData::CountTrigger(trigger) => {
+                    node.reads_items.push(item(trigger.item_id));
+                    match trigger.activate_group {
+                        true => node.activates.push(trigger.target_group),
+                        false => node.targets.push(trigger.target_group),
+                    }
+                },
+                ObjectData::InstantCountTrigger(trigger) => {
+                    node.reads_items.push(item(trigger.item_id));
+                    match trigger.activate_group {
+                        true => node.activates.push(trigger.target_group),
+                        false => node.targets.push(trigger.target_group),
+                    }
+                },
+                ObjectData::PickupTrigger(trigger) => node.writes_items.push(item(trigger.item_id)),
+                ObjectData::CollisionTrigger(trigger) => match trigger.activate_group {
+                    true => node.activates.push(trigger.target_group),
+                    false => node.targets.push(trigger.target_group),
+                },
+                ObjectData::TouchTrigger(trigger) => match trigger.toggle_mode {
+                    ToggleMode::Off => node.targets.push(trigger.target_group),
+                    _ => node.activates.push(trigger.target_group),
+                },
+                ObjectData::RandomTrigger(trigger) => node.activates.extend([trigger.group_1, trigger.group_2]),
+                ObjectData::SequenceTrigger(trigger) => node.activates.extend(trigger.steps.iter().map(|step| step.group)),
+                ObjectData::ItemEditTrigger(trigger) => {
+                    node.reads_items
+                        .extend([trigger.item_1, trigger.item_2].iter().copied().filter(|item| item.id != 0));
+                    node.writes_items.push(trigger.target);
+                },
+                ObjectData::ItemCompareTrigger(trigger) => {
+                    node.reads_items
+                        .extend([trigger.item_1, trigger.item_2].iter().copied().filter(|item| item.id != 0));
+                    node.activates.extend([trigger.true_group, trigger.false_group]);
+                },
+                ObjectData::CameraStaticTrigger(trigger) => node.targets.push(trigger.target_group),
+                ObjectData::CameraEdgeTrigger(trigger) => node.targets.push(trigger.target_group),
+                ObjectData::AreaTrigger(trigger) => node.targets.extend([trigger.target_group, trigger.center_group]),
+                _ => {},
+            }
+
+            node.activates.retain(|&group| group != 0);
+            node.targets.retain(|&group| group != 0);
+
+            nodes.push(node);
+        }
+
+        TriggerGraph { nodes }
+    }
+}
@@ -150,12 +150,18 @@ impl<'a, 'de> Deserializer<'de> for &'a mut IndexedDeserializer<'de> {
 
     delegate_to_from_str!(deserialize_f64, visit_f64);
 
-    fn deserialize_any<V>(self, _visitor: V) -> Result<<V as Visitor<'de>>::Value, Error<'de>>
+    fn deserialize_any<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Error<'de>>
     where
         V: Visitor<'de>,
     {
-        // the data format is by no means self describing
-        Err(Error::Unsupported("deserialize_any"))
+        // The data format is by no means self describing, however every token is a string. Treating
+        // tokens whose type we do not know as strings allows us to support `#[serde(flatten)]`, which
+        // is used to retain indices that do not map to any field.
+        let token = self.consume_token();
+
+        trace!("RobtopDeserializer::deserialize_any called on {:?}", token);
+
+        visitor.visit_borrowed_str(token.ok_or(Error::Eof)?)
     }
 
     fn deserialize_bool<V>(self, visitor: V) -> Result<<V as Visitor<'de>>::Value, Error<'de>>
@@ -4,8 +4,8 @@ mod thunk;
 
 pub use de::{error::Error as DeError, indexed::IndexedDeserializer};
 pub use ser::{error::Error as SerError, indexed::IndexedSerializer, request::RequestSerializer};
-use serde::{Deserializer, Serializer};
-pub use thunk::{Base64Decoder, PercentDecoder, ProcessError, Thunk, ThunkProcessor};
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+pub use thunk::{Base64Decoder, PercentDecoder, ProcessError, Processed, ProcessedRef, Thunk, ThunkProcessor};
 
 use std::{borrow::Cow, io::Write};
 
@@ -117,6 +117,19 @@ impl<'b, T: ThunkProcessor> InternalProxy for Thunk<'b, T> {
     }
 }
 
+impl InternalProxy for String {
+    type DeserializeProxy = String;
+    type SerializeProxy<'a> = &'a str;
+
+    fn to_serialize_proxy(&self) -> Self::SerializeProxy<'_> {
+        self
+    }
+
+    fn from_deserialize_proxy(from: Self::DeserializeProxy) -> Self {
+        from
+    }
+}
+
 impl<T: InternalProxy> InternalProxy for Option<T> {
     type DeserializeProxy = Option<T::DeserializeProxy>;
     type SerializeProxy<'a>
@@ -132,3 +145,27 @@ impl<T: InternalProxy> InternalProxy for Option<T> {
         from.map(|f| T::from_deserialize_proxy(f))
     }
 }
+
+/// Internal representation of a value that is itself stored in some indexed format (such as
+/// [`HsvValue`](crate::model::level::color::HsvValue)) inside of some other indexed value (such as
+/// an object string).
+#[derive(Debug, Clone)]
+pub struct Nested<T>(pub T);
+
+impl<'de, T: GJFormat<'de>> Deserialize<'de> for Nested<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::from_gj_str(<&str>::deserialize(deserializer)?)
+            .map(Nested)
+            .map_err(D::Error::custom)
+    }
+}
+
+impl<T: for<'de> GJFormat<'de>> Serialize for Nested<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut buffer = Vec::new();
+
+        self.0.write_gj(&mut buffer).map_err(S::Error::custom)?;
+
+        serializer.serialize_str(std::str::from_utf8(&buffer).map_err(S::Error::custom)?)
+    }
+}
@@ -1,7 +1,7 @@
 use crate::serde::ser::error::Error;
 use itoa::{Buffer, Integer};
 use serde::{
-    ser::{Error as _, Impossible, SerializeStruct},
+    ser::{Error as _, Impossible, SerializeMap, SerializeStruct},
     Serialize, Serializer,
 };
 use std::{fmt::Display, io::Write};
@@ -75,7 +75,7 @@ where
 impl<'a, W: Write> Serializer for &'a mut IndexedSerializer<W> {
     type Error = Error;
     type Ok = ();
-    type SerializeMap = Impossible<(), Error>;
+    type SerializeMap = Self;
     type SerializeSeq = Impossible<(), Error>;
     type SerializeStruct = Self;
     type SerializeStructVariant = Impossible<(), Error>;
@@ -214,7 +214,9 @@ impl<'a, W: Write> Serializer for &'a mut IndexedSerializer<W> {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Err(Error::Unsupported("serialize_map"))
+        // Maps are serialized just like structs, with the map keys taking the role of the indices.
+        // This is what `#[serde(flatten)]` fields get serialized as.
+        Ok(self)
     }
 
     fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
@@ -255,6 +257,26 @@ impl<'a, W: Write> SerializeStruct for &'a mut IndexedSerializer<W> {
     }
 }
 
+impl<W: Write> SerializeMap for &mut IndexedSerializer<W> {
+    type Error = Error;
+    type Ok = ();
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        if self.map_like {
+            key.serialize(&mut **self)?;
+        }
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::Serializer;
@@ -4,11 +4,11 @@ use base64::{
     DecodeError, DecodeSliceError, Engine,
 };
 use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
-use serde::{ser::Error as _, Deserialize, Serialize, Serializer};
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     borrow::{Borrow, Cow},
     mem,
-    num::ParseIntError,
+    num::{ParseFloatError, ParseIntError},
     str::Utf8Error,
     string::FromUtf8Error,
 };
@@ -46,6 +46,10 @@ pub enum ProcessError {
     #[error("{0}")]
     IntParse(#[from] ParseIntError),
 
+    /// Some error occurred when parsing a floating point number
+    #[error("{0}")]
+    FloatParse(#[from] ParseFloatError),
+
     /// Incorrect number of items when parsing a comma separated list (e.g. if an RGB list only has
     /// two entries)
     #[error("Incorrect number of items in comma separated list. Expected {expected}")]
@@ -107,6 +111,46 @@ where
     }
 }
 
+/// Deserialization proxy for values that are processed eagerly using some [`ThunkProcessor`]
+///
+/// This is used for fields of data that is itself the output of some processing step (such as the
+/// metadata section of level data, which only exists after decompression). There, no input with a
+/// sufficiently long lifetime exists for a [`Thunk`] to borrow from, so the value is processed right
+/// away instead.
+pub struct Processed<P: ThunkProcessor>(pub P::Output<'static>);
+
+impl<P: ThunkProcessor> Default for Processed<P>
+where
+    P::Output<'static>: Default,
+{
+    fn default() -> Self {
+        Processed(Default::default())
+    }
+}
+
+impl<'de, P: ThunkProcessor> Deserialize<'de> for Processed<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let unprocessed = <&str>::deserialize(deserializer)?;
+
+        P::from_unprocessed(Cow::Owned(unprocessed.to_owned()))
+            .map(Processed)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Serialization proxy for values that are processed eagerly using some [`ThunkProcessor`], the
+/// counterpart of [`Processed`]
+///
+/// Converting the value back into RobTop's representation happens during serialization, so that
+/// errors can be reported by the serializer.
+pub struct ProcessedRef<'a, P: ThunkProcessor>(pub &'a P::Output<'static>);
+
+impl<P: ThunkProcessor> Serialize for ProcessedRef<'_, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        P::as_unprocessed(self.0).map_err(S::Error::custom)?.serialize(serializer)
+    }
+}
+
 /// Trait describing how thunks should process their data
 ///
 /// This trait provides the means to translate from and into RobTop's representation for thunked
@@ -29,6 +29,12 @@ where
     }
 }
 
+/// Returns `true` if `value` is its type's default value. Intended for use with
+/// `skip_serializing_if` on indices older versions of the game do not write at all.
+pub(crate) fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
 pub(crate) mod default_to_none {
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -100,6 +106,30 @@ macro_rules! into_conversion {
     };
 }
 
+/// Implements [`InternalProxy`](crate::serde::InternalProxy) for some type that is the (owned)
+/// output of the given [`ThunkProcessor`](crate::ThunkProcessor), processing it eagerly during
+/// deserialization.
+#[macro_export]
+macro_rules! processed_conversion {
+    ($for:ty, $processor:ty) => {
+        impl $crate::serde::InternalProxy for $for {
+            type DeserializeProxy = $crate::serde::Processed<$processor>;
+            type SerializeProxy<'a>
+                = $crate::serde::ProcessedRef<'a, $processor>
+            where
+                Self: 'a;
+
+            fn to_serialize_proxy(&self) -> $crate::serde::ProcessedRef<'_, $processor> {
+                $crate::serde::ProcessedRef(self)
+            }
+
+            fn from_deserialize_proxy(from: $crate::serde::Processed<$processor>) -> $for {
+                from.0
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! dash_rs_newtype {
     ($name:ident) => {
@@ -0,0 +1,51 @@
+use base64::{engine::general_purpose::URL_SAFE, Engine};
+use dash_rs::{
+    model::level::{
+        builder::{CompressionScheme, LevelBuilder},
+        metadata::LevelMetadata,
+        object::{game_mode::GameMode, speed::Speed, LevelObject, ObjectData},
+    },
+    Thunk,
+};
+
+fn builder() -> LevelBuilder {
+    let mut portal = LevelObject::new(200, 315.0, 45.0);
+
+    portal.metadata = ObjectData::SpeedPortal {
+        checked: true,
+        speed: Speed::Slow,
+    };
+
+    LevelBuilder::new()
+        .meta(LevelMetadata {
+            starting_game_mode: GameMode::Ship,
+            ..LevelMetadata::default()
+        })
+        .objects((0..10).map(|i| LevelObject::new(1, 15.0 + 30.0 * i as f32, 15.0)))
+        .object(portal)
+}
+
+#[test]
+fn build_and_encode() {
+    for (scheme, magic) in [(CompressionScheme::Gzip, 0x1f), (CompressionScheme::Zlib, 0x78)] {
+        let builder = builder().compression(scheme).compression_level(1);
+        let encoded = builder.encode().unwrap();
+
+        assert_eq!(URL_SAFE.decode(&encoded).unwrap()[0], magic);
+
+        let mut thunk = builder.clone().into_thunk().unwrap();
+        let decoded = thunk.process().unwrap();
+
+        assert_eq!(*decoded, builder.build());
+        assert_eq!(decoded.objects.len(), 11);
+        assert_eq!(decoded.meta.starting_game_mode, GameMode::Ship);
+    }
+}
+
+#[test]
+fn builder_thunk_is_written_verbatim() {
+    let builder = builder().compression(CompressionScheme::Zlib);
+    let thunk: Thunk<_> = builder.clone().into_thunk().unwrap();
+
+    assert_eq!(thunk.as_unprocessed().unwrap(), builder.encode().unwrap());
+}
@@ -0,0 +1,141 @@
+use dash_rs::{
+    model::level::{metadata::LevelMetadata, object::LevelObject, Level, Objects},
+    GJFormat, Thunk,
+};
+use framework::{objects, objects_with_meta};
+use std::path::Path;
+
+mod framework;
+
+const COLORS: &str = "1_40_2_125_3_255_6_1000_7_1_15_1_18_0_8_1|1_0_2_0_3_0_6_3_7_0.5_15_1_18_0_8_1|";
+const REORDERED_COLORS: &str = "1_0_2_0_3_0_6_3_7_0.5_15_1_18_0_8_1|1_40_2_125_3_255_6_1000_7_1_15_1_18_0_8_1|";
+
+fn level() -> Objects {
+    objects_with_meta(
+        LevelMetadata::from_gj_str(&format!("kA2,1,kS38,{}", COLORS)).unwrap(),
+        &["1,1,2,15,3,15", "1,8,2,45,3,15,6,90", "1,901,2,75,3,15,51,1,10,0.5,28,30,29,0,30,0"],
+    )
+}
+
+fn reupload() -> Objects {
+    objects_with_meta(
+        // different color page and color channel order
+        LevelMetadata::from_gj_str(&format!("kS38,{},kA2,1,kS39,2", REORDERED_COLORS)).unwrap(),
+        &[
+            // different object order, explicit default values, different float formatting and noise
+            "3,15.0000001,1,901,2,75,51,1,10,0.5,28,30,29,0,30,0,4,0",
+            "1,1,3,15,2,15,32,1,6,0",
+            "1,8,2,4.5e1,3,15,6,90.0000002",
+        ],
+    )
+}
+
+#[test]
+fn canonical_form_is_layout_independent() {
+    let level = level();
+    let reupload = reupload();
+
+    assert_eq!(level.canonical_form().unwrap(), reupload.canonical_form().unwrap());
+    assert_eq!(level.content_hash().unwrap(), reupload.content_hash().unwrap());
+    assert_eq!(level.similarity(&reupload).unwrap(), 1.0);
+}
+
+#[test]
+fn content_hash_detects_changes() {
+    let original = level();
+    let mut modified = level();
+
+    modified.objects[0].x = 16.0;
+
+    assert_ne!(original.content_hash().unwrap(), modified.content_hash().unwrap());
+    assert_eq!(original.similarity(&modified).unwrap(), 0.5);
+}
+
+#[test]
+fn canonical_form_is_a_level_string() {
+    let level = level();
+    let canonical = level.canonical_form().unwrap();
+
+    let decoded = canonical
+        .split_terminator(';')
+        .skip(1)
+        .map(|object| LevelObject::from_gj_str(object).unwrap());
+    let mut expected = level.objects.clone();
+    let mut decoded: Vec<_> = decoded.collect();
+
+    expected.sort_by(|a, b| a.x.total_cmp(&b.x));
+    decoded.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+    assert_eq!(decoded, expected);
+
+    let (meta, objects) = canonical.split_once(';').unwrap();
+    let reparsed = Objects {
+        meta: LevelMetadata::from_gj_str(meta).unwrap(),
+        objects: decoded,
+    };
+
+    assert_eq!(reparsed.canonical_form().unwrap(), canonical);
+    assert_eq!(objects.split_terminator(';').count(), level.objects.len());
+}
+
+#[test]
+fn canonical_form_normalizes_values() {
+    let level = objects(&[
+        "1,1,2,15,3,15,6,-90,57,1.2",
+        // move trigger with a duration and offsets
+        "1,901,2,75,3,15,51,1,10,0.5,28,30,29,-0",
+    ]);
+    let reupload = objects(&[
+        "1,1,2,15,3,15,6,630,57,2.1.2",
+        "1,901,2,75,3,15,51,1,10,0.5000001,28,29.9999999,29,0",
+    ]);
+
+    assert_eq!(level.canonical_form().unwrap(), reupload.canonical_form().unwrap());
+    assert_eq!(level.objects[0].canonical_form().unwrap(), "1,1,2,15,3,15,57,1.2,6,270");
+
+    // numbers nested in the guidelines and color channels of the header
+    let level = objects_with_meta(
+        LevelMetadata::from_gj_str("kA14,1.5~0.8~,kS38,1_0_2_0_3_0_6_3_7_0.5_15_1_18_0_8_1|").unwrap(),
+        &[],
+    );
+    let reupload = objects_with_meta(
+        LevelMetadata::from_gj_str("kA14,1.5001~0.8~,kS38,1_0_2_0_3_0_6_3_7_0.5001_15_1_18_0_8_1|").unwrap(),
+        &[],
+    );
+
+    assert_ne!(level.meta, reupload.meta);
+    assert_eq!(level.canonical_form().unwrap(), reupload.canonical_form().unwrap());
+}
+
+#[test]
+fn similarity_counts_duplicates() {
+    let single = objects(&["1,1,2,15,3,15"]);
+    let double = objects(&["1,1,2,15,3,15", "1,1,2,15,3,15"]);
+    let empty = objects(&[]);
+
+    assert_eq!(single.similarity(&double).unwrap(), 0.5);
+    assert_eq!(double.similarity(&double).unwrap(), 1.0);
+    assert_eq!(single.similarity(&empty).unwrap(), 0.0);
+    assert_eq!(empty.similarity(&empty).unwrap(), 1.0);
+}
+
+#[test]
+fn canonical_form_survives_reencoding() {
+    let raw = std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("artifacts")
+            .join("level")
+            .join("11774780")
+            .join("raw"),
+    )
+    .unwrap();
+    let level: Level = Level::from_gj_str(&raw).unwrap();
+    let objects = level.level_data.level_data.into_processed().unwrap();
+    let hash = objects.content_hash().unwrap();
+
+    let encoded = Thunk::<Objects>::Processed(objects).as_unprocessed().unwrap().into_owned();
+    let reencoded = Thunk::<Objects>::Unprocessed(encoded.into()).into_processed().unwrap();
+
+    assert_eq!(reencoded.content_hash().unwrap(), hash);
+}
@@ -0,0 +1,74 @@
+use dash_rs::{
+    model::level::{
+        coins::CoinIssue,
+        object::{interactive::Coin, ObjectData},
+        Level,
+    },
+    GJFormat,
+};
+use framework::objects;
+use std::path::Path;
+
+mod framework;
+
+#[test]
+fn coins_are_typed() {
+    let objects = objects(&["1,1329,2,615,3,75", "1,142,2,15,3,15"]);
+
+    assert_eq!(objects.objects[0].metadata, ObjectData::Coin { coin: Coin::User });
+    assert_eq!(objects.objects[1].metadata, ObjectData::Coin { coin: Coin::Secret });
+
+    let mut buffer = Vec::new();
+    objects.objects[0].write_gj(&mut buffer).unwrap();
+
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), "1,1329,2,615,3,75");
+}
+
+#[test]
+fn coin_locations() {
+    let objects = objects(&["1,1329,2,934.74,3,75", "1,1,2,100,3,15", "1,1329,2,311.58,3,105"]);
+    let coins = objects.coins();
+
+    assert_eq!(coins.iter().map(|coin| coin.index).collect::<Vec<_>>(), vec![2, 0]);
+    assert_eq!((coins[0].x, coins[0].y), (311.58, 105.0));
+    assert!((coins[0].seconds - 1.0).abs() < 1e-4);
+    assert!((coins[1].seconds - 3.0).abs() < 1e-4);
+}
+
+#[test]
+fn coin_check() {
+    let objects = objects(&["1,1329,2,615,3,75", "1,1329,2,915,3,75", "1,142,2,15,3,15"]);
+
+    let report = objects.check_coins(2, true);
+    assert_eq!(report.user_coins, 2);
+    assert_eq!(report.issues, vec![CoinIssue::SecretCoins(1)]);
+
+    let report = objects.check_coins(3, false);
+    assert_eq!(
+        report.issues,
+        vec![CoinIssue::CountMismatch { expected: 3, found: 2 }, CoinIssue::SecretCoins(1)]
+    );
+
+    let report = self::objects(&["1,1,2,15,3,15"]).check_coins(0, true);
+    assert_eq!(report.issues, vec![CoinIssue::VerifiedWithoutCoins]);
+}
+
+#[test]
+fn coins_match_server_data() {
+    for id in ["897837", "11774780"] {
+        let raw = std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("artifacts")
+                .join("level")
+                .join(id)
+                .join("raw"),
+        )
+        .unwrap();
+        let mut level: Level = Level::from_gj_str(&raw).unwrap();
+        let report = level.check_coins().unwrap();
+
+        assert_eq!(report.user_coins, level.coin_amount as usize, "level {}", id);
+        assert!(report.is_consistent(), "level {}: {:?}", id, report.issues);
+    }
+}
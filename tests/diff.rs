@@ -0,0 +1,60 @@
+use dash_rs::model::level::metadata::LevelMetadata;
+use framework::{objects, objects_with_meta};
+
+mod framework;
+
+#[test]
+fn reordering_is_not_a_change() {
+    let old = objects(&["1,1,2,15,3,15", "1,8,2,45,3,15", "1,1,2,15,3,15"]);
+    let new = objects(&["1,8,2,45,3,15", "1,1,2,15,3,15", "1,1,2,15,3,15"]);
+
+    assert!(old.diff(&new).unwrap().is_empty());
+}
+
+#[test]
+fn diff_objects_and_header() {
+    let old = objects(&[
+        // unchanged block
+        "1,1,2,15,3,15",
+        // spike that gets recolored
+        "1,8,2,45,3,15",
+        // block that gets moved
+        "1,1,2,75,3,15,57,4",
+        // removed orb
+        "1,36,2,105,3,45",
+    ]);
+    let new = objects_with_meta(
+        LevelMetadata {
+            starting_mini: true,
+            ..LevelMetadata::default()
+        },
+        &[
+            "1,1,2,75,3,45,57,4",
+            "1,1,2,15,3,15",
+            "1,8,2,45,3,15,21,3",
+            // added pad
+            "1,35,2,135,3,15",
+        ],
+    );
+
+    let diff = old.diff(&new).unwrap();
+
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.removed[0].0, 3);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.added[0].1.id, 35);
+
+    assert_eq!(diff.modified.len(), 2);
+    assert_eq!((diff.modified[0].old_index, diff.modified[0].new_index), (2, 0));
+    assert_eq!(diff.modified[0].changes.len(), 1);
+    assert_eq!(diff.modified[0].changes[0].key, "3");
+    assert_eq!(diff.modified[0].changes[0].old.as_deref(), Some("15"));
+    assert_eq!(diff.modified[0].changes[0].new.as_deref(), Some("45"));
+    assert_eq!((diff.modified[1].old_index, diff.modified[1].new_index), (1, 2));
+    assert_eq!(diff.modified[1].changes[0].key, "21");
+    assert_eq!(diff.modified[1].changes[0].old, None);
+
+    assert_eq!(diff.header_changes.len(), 1);
+    assert_eq!(diff.header_changes[0].key, "kA3");
+    assert_eq!(diff.header_changes[0].new.as_deref(), Some("1"));
+}
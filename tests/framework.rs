@@ -7,7 +7,10 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use dash_rs::{GJFormat, IndexedDeserializer};
+use dash_rs::{
+    model::level::{metadata::LevelMetadata, object::LevelObject, Objects},
+    GJFormat, IndexedDeserializer,
+};
 use pretty_assertions::assert_eq;
 use serde::Deserialize;
 
@@ -25,6 +28,22 @@ pub fn load_test_units<D>(unit_dir: impl AsRef<Path>) -> BTreeMap<PathBuf, TestU
     map
 }
 
+/// Builds a level with the given header from the given object strings
+pub fn objects_with_meta(meta: LevelMetadata, object_strings: &[&str]) -> Objects {
+    Objects {
+        meta,
+        objects: object_strings
+            .iter()
+            .map(|object| LevelObject::from_gj_str(object).unwrap())
+            .collect(),
+    }
+}
+
+/// Builds a level with the default header from the given object strings
+pub fn objects(object_strings: &[&str]) -> Objects {
+    objects_with_meta(LevelMetadata::default(), object_strings)
+}
+
 pub trait Testable {
     type Target<'a>: GJFormat<'a> + Deserialize<'a> + Debug + for<'b> PartialEq<Self::Target<'b>>;
 
@@ -0,0 +1,147 @@
+use dash_rs::{
+    model::level::{
+        gameplay::GameplayState,
+        metadata::LevelMetadata,
+        object::{game_mode::GameMode, portal::GravityChange, speed::Speed, start_pos::StartPos, LevelObject, ObjectData},
+    },
+    GJFormat,
+};
+use framework::objects_with_meta;
+
+mod framework;
+
+/// Header of the test levels, starting in ball mode to check that the initial state is taken from it
+fn meta() -> LevelMetadata {
+    LevelMetadata {
+        starting_game_mode: GameMode::Ball,
+        ..LevelMetadata::default()
+    }
+}
+
+#[test]
+fn deserialize_portals() {
+    let ship = LevelObject::from_gj_str("1,13,2,105,3,45,13,1").unwrap();
+    let gravity = LevelObject::from_gj_str("1,2926,2,105,3,45").unwrap();
+    let teleport = LevelObject::from_gj_str("1,747,2,105,3,45,54,150").unwrap();
+
+    assert_eq!(
+        ship.metadata,
+        ObjectData::GameModePortal {
+            checked: true,
+            game_mode: GameMode::Ship
+        }
+    );
+    assert_eq!(
+        gravity.metadata,
+        ObjectData::GravityPortal {
+            gravity: GravityChange::Toggle
+        }
+    );
+    assert_eq!(teleport.metadata, ObjectData::TeleportPortal { offset_y: 150.0 });
+    assert!(teleport.unknown_properties.is_empty());
+}
+
+#[test]
+fn gameplay_state_at() {
+    let objects = objects_with_meta(
+        meta(),
+        &[
+            // wave portal
+            "1,660,2,300,3,45,13,1",
+            // block
+            "1,1,2,400,3,15",
+            // mini portal
+            "1,101,2,300,3,45",
+            // toggle gravity portals
+            "1,2926,2,500,3,45",
+            "1,2926,2,600,3,45",
+            // dual portal
+            "1,286,2,700,3,45",
+        ],
+    );
+
+    assert_eq!(
+        objects.gameplay_state_at(0.0),
+        GameplayState {
+            game_mode: GameMode::Ball,
+            ..GameplayState::default()
+        }
+    );
+    assert_eq!(
+        objects.gameplay_state_at(550.0),
+        GameplayState {
+            game_mode: GameMode::Wave,
+            mini: true,
+            gravity_inverted: true,
+            ..GameplayState::default()
+        }
+    );
+    assert_eq!(
+        objects.gameplay_state_at(1000.0),
+        GameplayState {
+            game_mode: GameMode::Wave,
+            mini: true,
+            dual: true,
+            ..GameplayState::default()
+        }
+    );
+
+    let sections = objects.gameplay_sections();
+
+    assert_eq!(
+        sections.iter().map(|section| section.start_x).collect::<Vec<_>>(),
+        vec![0.0, 300.0, 500.0, 600.0, 700.0]
+    );
+    assert_eq!(
+        sections[1].state,
+        GameplayState {
+            game_mode: GameMode::Wave,
+            mini: true,
+            ..GameplayState::default()
+        }
+    );
+}
+
+#[test]
+fn start_positions() {
+    let objects = objects_with_meta(
+        meta(),
+        &[
+            "1,31,2,915,3,105,kA2,4,kA3,1,kA4,3,kA8,0,kA10,0,kA11,1",
+            "1,1,2,400,3,15",
+            "1,31,2,315,3,45,kA2,1,kA3,0,kA4,1,kA8,1,kA11,0,kA21,1",
+        ],
+    );
+
+    let start_positions = objects.start_positions();
+
+    assert_eq!(start_positions.len(), 2);
+    assert_eq!(start_positions[0].0.x, 315.0);
+    assert_eq!(
+        *start_positions[0].1,
+        StartPos {
+            speed: Speed::Slow,
+            game_mode: GameMode::Ship,
+            dual: true,
+            disabled: true,
+            ..StartPos::default()
+        }
+    );
+    assert_eq!(
+        start_positions[1].1.gameplay_state(),
+        GameplayState {
+            game_mode: GameMode::Wave,
+            mini: true,
+            gravity_inverted: true,
+            ..GameplayState::default()
+        }
+    );
+
+    // kA10 is not modelled and must survive a round trip unchanged
+    let object = start_positions[1].0;
+    let mut buffer = Vec::new();
+    object.write_gj(&mut buffer).unwrap();
+
+    assert_eq!(&LevelObject::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap(), object);
+    assert_eq!(object.unknown_properties.get("kA10"), Some("0"));
+}
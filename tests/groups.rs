@@ -0,0 +1,110 @@
+use dash_rs::model::level::{object::ObjectData, Objects};
+use framework::objects;
+use std::collections::HashMap;
+
+mod framework;
+
+fn level() -> Objects {
+    objects(&[
+        // block in groups 4 and 20
+        "1,1,2,15,3,15,57,4.20",
+        // block in group 4, using color channel 3
+        "1,1,2,45,3,15,57,4,21,3",
+        // spawn trigger activating group 20
+        "1,1268,2,75,3,15,51,20",
+        // rotate trigger rotating group 4 around group 8
+        "1,1346,2,105,3,15,51,4,71,8,68,90",
+    ])
+}
+
+#[test]
+fn group_index() {
+    let objects = level();
+    let index = objects.group_index();
+
+    assert_eq!(index.groups().into_iter().collect::<Vec<_>>(), vec![4, 8, 20]);
+    assert_eq!(index.members(4), &[0, 1]);
+    assert_eq!(index.members(8), &[] as &[usize]);
+    assert_eq!(index.referrers(4), &[3]);
+    assert_eq!(index.referrers(20), &[2]);
+    assert_eq!(index.objects(20).map(|object| object.x).collect::<Vec<_>>(), vec![15.0]);
+}
+
+#[test]
+fn remap_groups() {
+    let mut objects = level();
+    let mapping: HashMap<u16, u16> = vec![(4, 40), (8, 20), (20, 0)].into_iter().collect();
+
+    objects.remap_groups(&mapping);
+
+    assert_eq!(objects.objects[0].groups, vec![40]);
+    assert_eq!(objects.objects[1].groups, vec![40]);
+
+    match (&objects.objects[2].metadata, &objects.objects[3].metadata) {
+        (ObjectData::SpawnTrigger(spawn), ObjectData::RotateTrigger(rotate)) => {
+            assert_eq!(spawn.target_group, 0);
+            assert_eq!((rotate.target_group, rotate.center_group), (40, 20));
+        },
+        metadata => panic!("unexpected metadata {:?}", metadata),
+    }
+}
+
+#[test]
+fn compact_groups() {
+    let mut objects = level();
+    let mapping = objects.compact_groups();
+
+    assert_eq!(mapping, vec![(4, 1), (8, 2), (20, 3)].into_iter().collect());
+    assert_eq!(objects.objects[0].groups, vec![1, 3]);
+    assert_eq!(objects.objects[3].referenced_groups(), vec![1, 2]);
+    assert_eq!(objects.group_index().groups().into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn free_ids() {
+    let objects = level();
+
+    assert_eq!(objects.free_groups(5), vec![1, 2, 3, 5, 6]);
+    assert_eq!(objects.free_colors(3), vec![1, 2, 4]);
+}
+
+#[test]
+fn untyped_trigger_references() {
+    let mut objects = objects(&[
+        // block in group 50
+        "1,1,2,15,3,15,57,50",
+        // stop trigger stopping group 50
+        "1,1616,2,45,3,15,51,50",
+    ]);
+
+    assert_eq!(objects.objects[1].metadata, ObjectData::Unknown);
+    assert_eq!(objects.objects[1].referenced_groups(), vec![50]);
+    assert_eq!(objects.group_index().referrers(50), &[1]);
+    assert!(!objects.free_groups(50).contains(&50));
+
+    let mapping = objects.compact_groups();
+
+    assert_eq!(mapping, vec![(50, 1)].into_iter().collect());
+    assert_eq!(objects.objects[0].groups, vec![1]);
+    assert_eq!(objects.objects[1].unknown_properties.get("51"), Some("1"));
+}
+
+#[test]
+fn move_target_position_group() {
+    // move triggers moving group 1, the first one to group 2 and the second one (which does not
+    // use its target position group) by an offset
+    let mut objects = objects(&["1,901,2,15,3,15,51,1,71,2,100,1", "1,901,2,45,3,15,51,1,71,3"]);
+
+    assert_eq!(objects.objects[0].referenced_groups(), vec![1, 2]);
+    assert_eq!(objects.objects[1].referenced_groups(), vec![1]);
+    assert_eq!(objects.free_groups(2), vec![3, 4]);
+
+    let mapping = objects.compact_groups();
+
+    assert_eq!(mapping, vec![(1, 1), (2, 2)].into_iter().collect());
+
+    match &objects.objects[1].metadata {
+        ObjectData::MoveTrigger(trigger) => assert_eq!(trigger.target_position_group, 3),
+        metadata => panic!("unexpected metadata {:?}", metadata),
+    }
+}
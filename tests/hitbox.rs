@@ -0,0 +1,116 @@
+use dash_rs::model::level::{
+    hitbox::Geometry,
+    metadata::LevelMetadata,
+    object::{ids::HitboxType, LevelObject},
+    stats::BoundingBox,
+    Objects,
+};
+use dash_rs::GJFormat;
+
+fn object(object: &str) -> LevelObject {
+    LevelObject::from_gj_str(object).unwrap()
+}
+
+fn area(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> BoundingBox {
+    BoundingBox {
+        min_x,
+        min_y,
+        max_x,
+        max_y,
+    }
+}
+
+fn assert_close(actual: BoundingBox, expected: BoundingBox) {
+    for (a, e) in [
+        (actual.min_x, expected.min_x),
+        (actual.min_y, expected.min_y),
+        (actual.max_x, expected.max_x),
+        (actual.max_y, expected.max_y),
+    ] {
+        assert!((a - e).abs() < 1e-3, "{:?} != {:?}", actual, expected);
+    }
+}
+
+#[test]
+fn world_space_hitboxes() {
+    let (kind, block) = object("1,1,2,15,3,15").hitbox().unwrap();
+
+    assert_eq!(kind, HitboxType::Solid);
+    assert_close(block.bounding_box(), area(0.0, 0.0, 30.0, 30.0));
+
+    // spike rotated by 90 degrees, so its 6x12 hitbox lies on its side
+    let (kind, spike) = object("1,8,2,45,3,15,6,90").hitbox().unwrap();
+
+    assert_eq!(kind, HitboxType::Hazard);
+    assert_close(spike.bounding_box(), area(39.0, 12.0, 51.0, 18.0));
+
+    // saw blade at half its size
+    let (_, saw) = object("1,88,2,100,3,100,32,0.5").hitbox().unwrap();
+
+    assert_eq!(
+        saw,
+        Geometry::Circle {
+            x: 100.0,
+            y: 100.0,
+            radius: 16.15
+        }
+    );
+
+    // a horizontally flipped slope rises to the left instead of the right
+    let (_, slope) = object("1,289,2,15,3,15,4,1").hitbox().unwrap();
+
+    assert!(slope.overlaps(&area(0.0, 25.0, 5.0, 30.0)));
+    assert!(!slope.overlaps(&area(25.0, 25.0, 30.0, 30.0)));
+
+    // decoration and triggers have no hitbox
+    assert_eq!(object("1,1764,2,15,3,15").hitbox(), None);
+    assert_eq!(object("1,899,2,15,3,15").hitbox(), None);
+}
+
+#[test]
+fn hitbox_index() {
+    let objects = Objects {
+        meta: LevelMetadata::default(),
+        objects: vec![
+            object("1,1,2,15,3,15"),
+            object("1,8,2,45,3,15"),
+            object("1,36,2,75,3,45"),
+            object("1,1764,2,15,3,15"),
+            // block stretched across several columns of the index
+            object("1,1,2,600,3,300,128,20"),
+        ],
+    };
+
+    let index = objects.hitbox_index();
+
+    assert_eq!(index.hitboxes().len(), 4);
+
+    let everything: Vec<_> = index.query(&area(0.0, 0.0, 60.0, 30.0)).iter().map(|hitbox| hitbox.index).collect();
+    let colliding: Vec<_> = index
+        .solids_and_hazards(&area(0.0, 0.0, 60.0, 30.0))
+        .iter()
+        .map(|hitbox| hitbox.index)
+        .collect();
+
+    assert_eq!(everything, vec![0, 1, 2]);
+    assert_eq!(colliding, vec![0, 1]);
+
+    // the spike's hitbox is much narrower than its grid cell
+    assert!(index.query(&area(31.0, 0.0, 41.0, 30.0)).is_empty());
+
+    let stretched: Vec<_> = index
+        .query(&area(800.0, 290.0, 810.0, 310.0))
+        .iter()
+        .map(|hitbox| hitbox.index)
+        .collect();
+
+    assert_eq!(stretched, vec![4]);
+    assert!(index.query(&area(1000.0, 0.0, 2000.0, 1000.0)).is_empty());
+
+    // unbounded and inverted areas
+    let unbounded = area(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::INFINITY, f32::INFINITY);
+
+    assert_eq!(index.query(&unbounded).len(), 4);
+    assert_eq!(index.query(&area(f32::MIN, f32::MIN, f32::MAX, f32::MAX)).len(), 4);
+    assert!(index.query(&area(60.0, 0.0, 0.0, 30.0)).is_empty());
+}
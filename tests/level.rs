@@ -1,7 +1,13 @@
-use std::path::Path;
+use std::{io::Read, path::Path};
 
-use dash_rs::model::level::Level;
+use base64::{engine::general_purpose::URL_SAFE, Engine};
+use dash_rs::{
+    model::level::{object::ObjectData, Level},
+    GJFormat,
+};
+use flate2::read::{GzDecoder, ZlibDecoder};
 use framework::load_test_units;
+use pretty_assertions::assert_eq;
 
 mod framework;
 
@@ -44,7 +50,14 @@ impl framework::Testable for FullLevelTester {
         if let Some(ref mut hunk) = level.description {
             hunk.process().unwrap();
         }
-        level.level_data.level_data.process().unwrap();
+        let objects = level.level_data.level_data.process().unwrap();
+
+        for object in &mut objects.objects {
+            if let ObjectData::Text { ref mut text, .. } = object.metadata {
+                text.process().unwrap();
+            }
+        }
+
         level.level_data.password.process().unwrap();
     }
 }
@@ -57,7 +70,42 @@ fn test_full_level() {
         println!("Testing case {:?}", path);
 
         unit.test_consistency();
-        // Cannot do round trip testing for onw, as the level data handling in dash-rs is incomplete
-        // (to put it nicely)
+        // Cannot do the usual round trip testing, as re-compressing the level data will not
+        // reproduce the exact same bytes. Instead, compare the decompressed level strings.
+        test_level_data_roundtrip(&path);
+    }
+}
+
+fn decompress_level_data(level_data: &str) -> String {
+    let decoded = URL_SAFE.decode(level_data).unwrap();
+    let mut decompressed = String::new();
+
+    match &decoded[..2] {
+        [0x1f, 0x8b] => GzDecoder::new(&decoded[..]).read_to_string(&mut decompressed).unwrap(),
+        _ => ZlibDecoder::new(&decoded[..]).read_to_string(&mut decompressed).unwrap(),
+    };
+
+    decompressed
+}
+
+fn test_level_data_roundtrip(unit: &Path) {
+    let raw = std::fs::read_to_string(unit.join("raw")).unwrap();
+    let mut level: Level = Level::from_gj_str(&raw).unwrap();
+
+    let original = level.level_data.level_data.as_unprocessed().unwrap().into_owned();
+    level.level_data.level_data.process().unwrap();
+    let reencoded = level.level_data.level_data.as_unprocessed().unwrap();
+
+    let original = decompress_level_data(&original);
+    let reencoded = decompress_level_data(&reencoded);
+
+    let original: Vec<_> = original.split(';').collect();
+    let reencoded: Vec<_> = reencoded.split(';').collect();
+
+    assert_eq!(original.len(), reencoded.len());
+
+    // Compare section by section, so that pretty_assertions only has to diff short strings
+    for (original, reencoded) in original.into_iter().zip(reencoded) {
+        assert_eq!(original, reencoded);
     }
 }
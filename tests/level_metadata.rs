@@ -0,0 +1,124 @@
+use dash_rs::{
+    model::level::{
+        color::{HsvValue, PlayerColor, BACKGROUND_CHANNEL},
+        metadata::{BackgroundTexture, Font, GroundTexture, Guideline, LevelMetadata, MiddlegroundTexture},
+        object::{game_mode::GameMode, speed::Speed},
+    },
+    GJFormat,
+};
+
+const HEADER: &str =
+    "kA13,0,kA15,0,kA16,0,kA14,1.5~0.8~3~0.9~,kA6,12,kA7,3,kA17,1,kA18,4,kS39,0,kA2,2,kA3,1,kA8,0,kA4,1,kA9,0,kA10,0,kA11,0";
+
+#[test]
+fn deserialize_header() {
+    let metadata = LevelMetadata::from_gj_str(HEADER).unwrap();
+
+    assert_eq!(metadata.starting_speed, Speed::Slow);
+    assert_eq!(metadata.starting_game_mode, GameMode::Ball);
+    assert!(metadata.starting_mini);
+    assert_eq!(metadata.background_texture, BackgroundTexture::Index(12));
+    assert_eq!(metadata.ground_texture, GroundTexture::Index(3));
+    assert_eq!(metadata.middleground_texture, MiddlegroundTexture::None);
+    assert_eq!(metadata.font, Font::Index(4));
+    assert_eq!(
+        metadata.guidelines,
+        vec![Guideline { time: 1.5, color: 0.8 }, Guideline { time: 3.0, color: 0.9 }]
+    );
+    assert!(!metadata.platformer);
+    assert_eq!(metadata.unknown_properties.iter().collect::<Vec<_>>(), vec![("kA9", "0")]);
+}
+
+#[test]
+fn unknown_texture_indices() {
+    let metadata = LevelMetadata::from_gj_str("kA6,0,kA7,200,kA18,255").unwrap();
+
+    assert_eq!(metadata.background_texture, BackgroundTexture::Default);
+    assert_eq!(metadata.ground_texture, GroundTexture::Unknown(200));
+    assert_eq!(metadata.font, Font::Unknown(255));
+
+    let mut buffer = Vec::new();
+    metadata.write_gj(&mut buffer).unwrap();
+
+    let restored = LevelMetadata::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+    assert_eq!(restored.ground_texture, GroundTexture::Unknown(200));
+    assert_eq!(restored.font, Font::Unknown(255));
+}
+
+#[test]
+fn header_roundtrip() {
+    let metadata = LevelMetadata::from_gj_str(HEADER).unwrap();
+
+    let mut buffer = Vec::new();
+    metadata.write_gj(&mut buffer).unwrap();
+
+    let restored = LevelMetadata::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+    assert_eq!(metadata, restored);
+}
+
+const COLORS: &str = "kS38,1_40_2_125_3_255_11_255_12_255_13_255_6_1000_7_1_15_1_18_0_8_1|1_0_2_0_3_0_11_255_12_255_13_255_4_-1_6_3_5_1_7_0.5_15_1_18_0_8_1|1_143_2_0_3_63_4_2_6_1_9_3_10_0a2a1.52a0a0_17_1_7_1_15_0_8_1|";
+
+#[test]
+fn decode_color_channels() {
+    let metadata = LevelMetadata::from_gj_str(COLORS).unwrap();
+    let channels = &metadata.color_channels;
+
+    assert_eq!(channels.len(), 3);
+
+    assert_eq!(channels[0].id, BACKGROUND_CHANNEL);
+    assert_eq!((channels[0].red, channels[0].green, channels[0].blue), (40, 125, 255));
+    assert_eq!(channels[0].opacity, 1.0);
+    assert!(!channels[0].blending);
+
+    assert_eq!(channels[1].id, 3);
+    assert_eq!(channels[1].player_color, PlayerColor::None);
+    assert_eq!(channels[1].opacity, 0.5);
+    assert!(channels[1].blending);
+
+    assert_eq!(channels[2].player_color, PlayerColor::Player2);
+    assert_eq!(channels[2].copied_channel, 3);
+    assert_eq!(
+        channels[2].copied_hsv,
+        Some(HsvValue {
+            saturation: 2.0,
+            value: 1.52,
+            ..HsvValue::default()
+        })
+    );
+    assert!(channels[2].copy_opacity);
+}
+
+// Taken from level 11774780, with an additional channel using player color `0`
+const REAL_COLORS: &str = "kS38,1_32_2_0_3_14_4_-1_6_1000_7_1_15_0_8_1|1_45_2_0_3_30_11_255_12_255_13_255_4_-1_6_1009_7_1_15_1_8_1|1_143_2_0_3_63_4_-1_6_1_5_1_7_1_15_0_10_0a2a1.52a0a0_8_1|1_192_2_40_3_0_4_-1_6_4_5_1_7_1_15_0_10_60a1.4a1a0a0_8_1|1_0_2_0_3_0_11_255_12_255_13_255_4_0_6_6_7_1_15_1_8_1|";
+
+#[test]
+fn color_channels_reencode_unchanged() {
+    let metadata = LevelMetadata::from_gj_str(REAL_COLORS).unwrap();
+
+    assert_eq!(metadata.color_channels[4].player_color, PlayerColor::Unknown(0));
+
+    let mut buffer = Vec::new();
+    metadata.write_gj(&mut buffer).unwrap();
+
+    // the header's other fields are always written, so only compare the color channels
+    let reencoded = std::str::from_utf8(&buffer).unwrap();
+
+    assert_eq!(reencoded.split(',').nth(1), REAL_COLORS.split(',').nth(1));
+}
+
+#[test]
+fn color_channels_roundtrip() {
+    let mut metadata = LevelMetadata::from_gj_str(COLORS).unwrap();
+
+    metadata.color_channels[0].red = 0;
+
+    let mut buffer = Vec::new();
+    metadata.write_gj(&mut buffer).unwrap();
+
+    let restored = LevelMetadata::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+    assert_eq!(restored.color_channels, metadata.color_channels);
+    assert_eq!(restored.color_channels[0].red, 0);
+}
@@ -0,0 +1,359 @@
+use dash_rs::{
+    model::level::{
+        color::HsvValue,
+        metadata::LevelMetadata,
+        object::{
+            ids::{self, HitboxType},
+            interactive::{DashOrb, Orb, Pad},
+            trigger::{Easing, MoveTrigger, PulseMode, PulseTarget, Shader, ShaderTrigger, TriggerActivation},
+            LevelObject, ObjectCategory, ObjectData, ZLayer,
+        },
+        Objects,
+    },
+    GJFormat,
+};
+
+const DECORATION: &str =
+    "1,1764,2,2031.25,3,565.9,6,-170,20,4,21,513,22,8,24,3,25,-10,32,1.78,41,1,43,-69a1a1a0a0,57,621.627,61,6,64,1,67,1,108,12";
+
+#[test]
+fn deserialize_common_properties() {
+    let object = LevelObject::from_gj_str(DECORATION).unwrap();
+
+    assert_eq!(object.id, 1764);
+    assert_eq!(object.rotation, -170.0);
+    assert_eq!(object.scale, 1.78);
+    assert_eq!(object.groups, vec![621, 627]);
+    assert_eq!(object.editor_layer, 4);
+    assert_eq!(object.editor_layer_2, 6);
+    assert_eq!(object.z_layer, ZLayer::B1);
+    assert_eq!(object.z_order, -10);
+    assert_eq!(object.main_color, 513);
+    assert_eq!(object.detail_color, 8);
+    assert_eq!(
+        object.main_hsv,
+        Some(HsvValue {
+            hue: -69.0,
+            ..HsvValue::default()
+        })
+    );
+    assert_eq!(object.detail_hsv, None);
+    assert!(object.dont_fade);
+    assert!(object.dont_enter);
+    assert_eq!(object.linked_group, 12);
+}
+
+#[test]
+fn common_properties_roundtrip() {
+    let object = LevelObject::from_gj_str(DECORATION).unwrap();
+
+    let mut buffer = Vec::new();
+    object.write_gj(&mut buffer).unwrap();
+
+    let restored = LevelObject::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+    assert_eq!(object, restored);
+}
+
+#[test]
+fn unknown_properties_are_retained_in_order() {
+    // 999 and 998 are not known to dash-rs. Index 13 is, but is not applicable to block objects
+    let object = LevelObject::from_gj_str("1,1,2,15,3,15,999,a,13,1,998,b").unwrap();
+
+    assert_eq!(
+        object.unknown_properties.iter().collect::<Vec<_>>(),
+        vec![("999", "a"), ("13", "1"), ("998", "b")]
+    );
+
+    let mut buffer = Vec::new();
+    object.write_gj(&mut buffer).unwrap();
+
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), "1,1,2,15,3,15,999,a,13,1,998,b");
+}
+
+#[test]
+fn explicit_default_values_are_retained() {
+    // 6 (rotation) and 21 (main color) hold their default values, and are written out of order
+    let input = "1,1,21,0,2,15,3,15,6,0,57,3";
+    let mut object = LevelObject::from_gj_str(input).unwrap();
+
+    let mut buffer = Vec::new();
+    object.write_gj(&mut buffer).unwrap();
+
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), input);
+
+    // Changing a value keeps the key in place
+    object.rotation = 90.0;
+
+    let mut buffer = Vec::new();
+    object.write_gj(&mut buffer).unwrap();
+
+    assert_eq!(std::str::from_utf8(&buffer).unwrap(), "1,1,21,0,2,15,3,15,6,90,57,3");
+}
+
+const MOVE_TRIGGER: &str = "1,901,2,1485,3,105,20,2,57,4,36,1,51,12,10,0.5,28,30,29,-60,30,2,85,2,58,1,11,1";
+const PULSE_TRIGGER: &str = "1,1006,2,765,3,345,36,1,48,1,49,40a1a0.5a0a0,50,3,51,7,52,1,45,0.1,47,0.4,86,1";
+
+#[test]
+fn deserialize_move_trigger() {
+    let object = LevelObject::from_gj_str(MOVE_TRIGGER).unwrap();
+
+    assert_eq!(
+        object.metadata,
+        ObjectData::MoveTrigger(MoveTrigger {
+            target_group: 12,
+            duration: 0.5,
+            offset_x: 30.0,
+            offset_y: -60.0,
+            easing: Easing::EaseIn,
+            easing_rate: 2.0,
+            lock_to_player_x: true,
+            lock_to_player_y: false,
+            use_target: false,
+            target_position_group: 0,
+            activation: TriggerActivation {
+                touch_triggered: true,
+                ..TriggerActivation::default()
+            }
+        })
+    );
+}
+
+#[test]
+fn deserialize_pulse_trigger() {
+    let object = LevelObject::from_gj_str(PULSE_TRIGGER).unwrap();
+
+    let ObjectData::PulseTrigger(trigger) = object.metadata else {
+        panic!("expected pulse trigger, got {:?}", object.metadata)
+    };
+
+    assert_eq!(trigger.target, PulseTarget::Group(7));
+    assert_eq!(
+        trigger.mode,
+        PulseMode::Hsv {
+            copied_channel: 3,
+            hsv: HsvValue {
+                hue: 40.0,
+                value: 0.5,
+                ..HsvValue::default()
+            }
+        }
+    );
+    assert_eq!(trigger.fade_in, 0.1);
+    assert_eq!(trigger.hold, 0.0);
+    assert_eq!(trigger.fade_out, 0.4);
+    assert!(trigger.exclusive);
+}
+
+#[test]
+fn trigger_roundtrip() {
+    for trigger in [MOVE_TRIGGER, PULSE_TRIGGER] {
+        let object = LevelObject::from_gj_str(trigger).unwrap();
+
+        let mut buffer = Vec::new();
+        object.write_gj(&mut buffer).unwrap();
+
+        let restored = LevelObject::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+        assert_eq!(object, restored);
+    }
+}
+
+#[test]
+fn logic_trigger_roundtrip() {
+    let triggers = [
+        "1,1268,2,45,3,15,51,5,63,0.5,62,1,87,1",
+        "1,1611,2,165,3,15,80,3,77,-2,51,11,56,1,104,1",
+        "1,1811,2,165,3,15,80,3,77,4,88,2,51,11",
+        "1,1815,2,165,3,15,80,1,95,2,51,11,56,1,93,1",
+        "1,1595,2,165,3,15,51,11,81,1,82,2,89,1",
+        "1,1912,2,165,3,15,51,11,71,12,10,30",
+        "1,3607,2,165,3,15,435,4.1.5.2.6.1,436,0.2,437,1",
+        "1,3619,2,165,3,15,80,1,95,2,51,3,476,2,478,3,479,1.5,480,1,481,3,482,4",
+        "1,3620,2,165,3,15,80,1,95,2,479,2,483,3,480,1,481,2,482,5,51,10,71,11",
+    ];
+
+    for trigger in triggers {
+        let object = LevelObject::from_gj_str(trigger).unwrap();
+
+        assert!(
+            object.metadata.activation().is_some(),
+            "{:?} was not recognized as trigger",
+            object.metadata
+        );
+        assert!(
+            object.unknown_properties.is_empty(),
+            "{:?} has unmapped properties",
+            object.unknown_properties
+        );
+
+        let mut buffer = Vec::new();
+        object.write_gj(&mut buffer).unwrap();
+
+        let restored = LevelObject::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+        assert_eq!(object, restored);
+    }
+}
+
+#[test]
+fn deserialize_shader_trigger() {
+    let object = LevelObject::from_gj_str("1,2909,2,165,3,15,10,0.5,30,2,11,1").unwrap();
+
+    assert_eq!(
+        object.metadata,
+        ObjectData::ShaderTrigger(ShaderTrigger {
+            shader: Shader::Glitch,
+            duration: 0.5,
+            easing: Easing::EaseIn,
+            easing_rate: 0.0,
+            activation: TriggerActivation {
+                touch_triggered: true,
+                ..TriggerActivation::default()
+            },
+        })
+    );
+}
+
+#[test]
+fn camera_and_audio_trigger_roundtrip() {
+    let triggers = [
+        "1,1913,2,165,3,15,371,1.5,10,0.5,30,1,85,2",
+        "1,1914,2,165,3,15,71,4,10,1,110,1",
+        "1,1916,2,165,3,15,28,30,29,-15,10,2",
+        "1,2925,2,165,3,15,111,1",
+        "1,2062,2,165,3,15,51,7,164,3",
+        "1,1934,2,165,3,15,392,10007,406,0.5,404,2",
+        "1,3602,2,165,3,15,392,4451,406,0.8",
+        "1,3008,2,165,3,15,51,3,71,4",
+        "1,1935,2,165,3,15,120,0.5",
+        "1,2066,2,165,3,15,148,2",
+    ];
+
+    for trigger in triggers {
+        let object = LevelObject::from_gj_str(trigger).unwrap();
+
+        assert!(
+            object.metadata.activation().is_some(),
+            "{:?} was not recognized as trigger",
+            object.metadata
+        );
+        assert!(
+            object.unknown_properties.is_empty(),
+            "{:?} has unmapped properties",
+            object.unknown_properties
+        );
+
+        let mut buffer = Vec::new();
+        object.write_gj(&mut buffer).unwrap();
+
+        let restored = LevelObject::from_gj_str(std::str::from_utf8(&buffer).unwrap()).unwrap();
+
+        assert_eq!(object, restored);
+    }
+}
+
+#[test]
+fn object_catalogue() {
+    let spike = ids::lookup(8).unwrap();
+
+    assert_eq!(spike.name, "Spike");
+    assert_eq!(spike.category, ObjectCategory::Hazard);
+    assert_eq!(spike.hitbox, HitboxType::Hazard);
+    assert!(spike.counts_toward_limit);
+
+    let portal = ids::lookup(ids::SHIP_PORTAL).unwrap();
+
+    assert_eq!(portal.name, "Ship Portal");
+    assert_eq!(portal.hitbox, HitboxType::Special);
+    assert!(!ids::lookup(ids::START_POS).unwrap().counts_toward_limit);
+    assert_eq!(ids::lookup(1764), None);
+
+    assert!(ids::catalogue().windows(2).all(|pair| pair[0].id < pair[1].id));
+    assert!(ids::catalogue().iter().all(|info| ids::lookup(info.id) == Some(info)));
+
+    assert_eq!(LevelObject::new(1, 15.0, 15.0).category(), ObjectCategory::Solid);
+    assert_eq!(LevelObject::new(1764, 15.0, 15.0).category(), ObjectCategory::Decoration);
+}
+
+#[test]
+fn pads_and_orbs() {
+    let cases = [
+        (
+            "1,35,2,15,3,15",
+            ObjectData::Pad {
+                pad: Pad::Yellow,
+                multi_activate: false,
+            },
+        ),
+        (
+            "1,1332,2,15,3,15,99,1",
+            ObjectData::Pad {
+                pad: Pad::Red,
+                multi_activate: true,
+            },
+        ),
+        (
+            "1,1330,2,15,3,15",
+            ObjectData::Orb {
+                orb: Orb::Black,
+                multi_activate: false,
+            },
+        ),
+        (
+            "1,1751,2,15,3,15,99,1",
+            ObjectData::DashOrb {
+                orb: DashOrb::Pink,
+                multi_activate: true,
+            },
+        ),
+    ];
+
+    for &(string, ref data) in cases.iter() {
+        let object = LevelObject::from_gj_str(string).unwrap();
+
+        assert_eq!(&object.metadata, data);
+        assert!(object.unknown_properties.is_empty());
+
+        let mut buffer = Vec::new();
+        object.write_gj(&mut buffer).unwrap();
+
+        assert_eq!(std::str::from_utf8(&buffer).unwrap(), string);
+    }
+}
+
+#[test]
+fn text_objects() {
+    let objects = Objects {
+        meta: LevelMetadata::default(),
+        objects: [
+            "1,914,2,195.6,3,135,32,0.66,31,bmVlZHMgYQ==",
+            "1,914,2,195.6,3,165,31,RXZlcnkgbGV2ZWw=,488,2",
+            "1,914,2,79,3,133,31,Ig==",
+            "1,1,2,15,3,15",
+        ]
+        .iter()
+        .map(|object| LevelObject::from_gj_str(object).unwrap())
+        .collect(),
+    };
+
+    match objects.objects[1].metadata {
+        ObjectData::Text { ref text, kerning } => {
+            assert_eq!(text.as_processed().unwrap().as_ref(), "Every level");
+            assert_eq!(kerning, 2);
+        },
+        ref data => panic!("expected text object, got {:?}", data),
+    }
+
+    let mut buffer = Vec::new();
+    objects.objects[1].write_gj(&mut buffer).unwrap();
+
+    assert_eq!(
+        std::str::from_utf8(&buffer).unwrap(),
+        "1,914,2,195.6,3,165,31,RXZlcnkgbGV2ZWw=,488,2"
+    );
+
+    let texts: Vec<_> = objects.texts().unwrap().into_iter().map(|(_, text)| text).collect();
+
+    assert_eq!(texts, vec!["\"", "Every level", "needs a"]);
+}
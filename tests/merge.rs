@@ -0,0 +1,141 @@
+use dash_rs::{
+    model::level::{
+        metadata::LevelMetadata,
+        object::{LevelObject, ObjectData},
+        stats::BoundingBox,
+        Objects,
+    },
+    GJFormat, Thunk,
+};
+use framework::objects_with_meta;
+
+mod framework;
+
+fn target() -> Objects {
+    objects_with_meta(
+        LevelMetadata::from_gj_str("kS38,1_255_2_0_3_0_6_1_7_1_15_1_18_0_8_1|").unwrap(),
+        &[
+            // block in group 1, using color channel 1
+            "1,1,2,15,3,15,57,1,21,1",
+            // collision block 1
+            "1,1816,2,45,3,15,80,1",
+            // pickup trigger adding to item 2
+            "1,1817,2,75,3,15,80,2,77,1",
+        ],
+    )
+}
+
+fn source() -> Objects {
+    objects_with_meta(
+        // channel 1 copies channel 3, the background channel is never copied
+        LevelMetadata::from_gj_str(
+            "kS38,1_0_2_0_3_0_6_1_9_3_7_1_15_1_18_0_8_1|1_0_2_255_3_0_6_3_7_1_15_1_18_0_8_1|1_40_2_125_3_255_6_1000_7_1_15_1_18_0_8_1|",
+        )
+        .unwrap(),
+        &[
+            // block in groups 1 and 7, using color channel 1
+            "1,1,2,15,3,15,57,1.7,21,1",
+            // move trigger moving group 1
+            "1,901,2,45,3,15,51,1,10,0.5,28,30,29,0,30,0",
+            // collision block 1
+            "1,1816,2,75,3,15,80,1",
+            // collision trigger between blocks 1 and 5, activating group 7
+            "1,1815,2,105,3,15,80,1,95,5,51,7,56,1",
+            // pickup trigger adding to item 2
+            "1,1817,2,135,3,15,80,2,77,1",
+            // block far away, not part of the selection
+            "1,1,2,3015,3,15,57,9",
+        ],
+    )
+}
+
+#[test]
+fn select() {
+    let source = source();
+    let area = BoundingBox {
+        min_x: 0.0,
+        min_y: 0.0,
+        max_x: 135.0,
+        max_y: 30.0,
+    };
+
+    assert_eq!(source.select_area(&area).indices(), &[0, 1, 2, 3, 4]);
+    assert_eq!(source.select_group(7).indices(), &[0]);
+    assert_eq!(source.select(|object| object.id == 1816).indices(), &[2]);
+}
+
+#[test]
+fn merge_reassigns_colliding_ids() {
+    let source = source();
+    let mut target = target();
+    let selection = source.select(|object| object.x < 1000.0);
+
+    let merged = target.merge(&source, &selection, 300.0, 60.0).unwrap();
+
+    assert_eq!(merged.selection.indices(), &[3, 4, 5, 6, 7]);
+    assert_eq!(merged.groups, vec![(1, 2), (7, 7)].into_iter().collect());
+    assert_eq!(merged.colors, vec![(1, 2), (3, 3)].into_iter().collect());
+    assert_eq!(merged.items, vec![(2, 1)].into_iter().collect());
+    assert_eq!(merged.collision_blocks, vec![(1, 2), (5, 5)].into_iter().collect());
+
+    let block = &target.objects[3];
+    assert_eq!((block.x, block.y), (315.0, 75.0));
+    assert_eq!(block.groups, vec![2, 7]);
+    assert_eq!(block.main_color, 2);
+
+    assert_eq!(target.objects[4].referenced_groups(), vec![2]);
+    assert_eq!(target.objects[5].unknown_properties.get("80"), Some("2"));
+
+    match (&target.objects[6].metadata, &target.objects[7].metadata) {
+        (ObjectData::CollisionTrigger(collision), ObjectData::PickupTrigger(pickup)) => {
+            assert_eq!((collision.block_a, collision.block_b, collision.target_group), (2, 5, 7));
+            assert_eq!(pickup.item_id, 1);
+        },
+        metadata => panic!("unexpected metadata {:?}", metadata),
+    }
+
+    // the existing objects are untouched
+    assert_eq!(target.objects[0].groups, vec![1]);
+    assert_eq!(target.objects[1].unknown_properties.get("80"), Some("1"));
+
+    let channels = &target.meta.color_channels;
+    assert_eq!(
+        channels
+            .iter()
+            .map(|channel| (channel.id, channel.copied_channel))
+            .collect::<Vec<_>>(),
+        vec![(1, 0), (2, 3), (3, 0)]
+    );
+    assert_eq!(channels[0].red, 255);
+    assert_eq!(channels[2].green, 255);
+}
+
+#[test]
+fn merge_avoids_groups_of_untyped_triggers() {
+    let source = source();
+    let mut target = target();
+
+    // stop trigger stopping group 2, which no object is part of
+    target.objects.push(LevelObject::from_gj_str("1,1616,2,105,3,15,51,2").unwrap());
+
+    let merged = target.merge(&source, &source.select_group(1), 0.0, 0.0).unwrap();
+
+    assert_eq!(merged.groups, vec![(1, 3), (7, 7)].into_iter().collect());
+    assert_eq!(target.objects[4].groups, vec![3, 7]);
+    assert_eq!(target.objects[3].unknown_properties.get("51"), Some("2"));
+}
+
+#[test]
+fn merged_level_can_be_encoded() {
+    let source = source();
+    let mut target = target();
+
+    target.merge(&source, &source.select_group(9), 0.0, 0.0).unwrap();
+
+    let encoded = Thunk::<Objects>::Processed(target).as_unprocessed().unwrap().into_owned();
+    let mut decoded = Thunk::<Objects>::Unprocessed(encoded.into());
+    let decoded = decoded.process().unwrap();
+
+    assert_eq!(decoded.objects.len(), 4);
+    assert_eq!(decoded.objects[3].groups, vec![9]);
+}
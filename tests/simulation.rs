@@ -0,0 +1,106 @@
+use dash_rs::model::level::{
+    builder::LevelBuilder,
+    object::{
+        game_mode::GameMode,
+        ids,
+        interactive::{Orb, Pad},
+        LevelObject, ObjectData,
+    },
+    simulation::{Input, Outcome},
+    Objects,
+};
+
+/// A level consisting of three spikes next to each other, and a decoration object marking the
+/// level's end
+fn spikes(pad: bool) -> Objects {
+    let mut builder = LevelBuilder::new();
+
+    if pad {
+        builder.push(LevelObject {
+            metadata: ObjectData::Pad {
+                pad: Pad::Yellow,
+                multi_activate: false,
+            },
+            ..LevelObject::new(ids::YELLOW_PAD, 195.0, 15.0)
+        });
+    }
+
+    builder
+        .objects([255.0, 285.0, 315.0].iter().map(|&x| LevelObject::new(8, x, 15.0)))
+        .object(LevelObject::new(1764, 600.0, 15.0))
+        .build()
+}
+
+#[test]
+fn simulate_cube() {
+    let level = spikes(false);
+    let simulator = level.simulator();
+
+    let result = simulator.run(&[]);
+
+    match result.outcome {
+        Outcome::Died { x, object, .. } => {
+            assert_eq!(object, Some(0));
+            assert!(x > 230.0 && x < 260.0, "died at {}", x);
+        },
+        outcome => panic!("expected player to die, got {:?}", outcome),
+    }
+
+    // a single jump is not long enough to clear three spikes
+    assert!(!simulator.run(&Input::click(200.0, 5.0)).survived());
+
+    // holding jumps again right after landing
+    assert!(!simulator.run(&[Input { x: 200.0, hold: true }]).survived());
+}
+
+#[test]
+fn simulate_pad() {
+    let level = spikes(true);
+    let result = level.simulator().record_path(true).run(&[]);
+
+    assert!(result.survived());
+    assert_eq!(result.path.len() as u32, result.ticks);
+    assert!(result.path.iter().any(|&(_, y)| y > 100.0));
+    // the player lands back on the ground
+    assert_eq!(result.path.last().unwrap().1, 15.0);
+}
+
+#[test]
+fn simulate_ship() {
+    let mut level = spikes(false);
+
+    level.meta.starting_game_mode = GameMode::Ship;
+
+    // the ship flies low enough to crash into the spikes if it does not pull up early enough
+    assert!(!level.simulator().run(&[]).survived());
+
+    let result = level
+        .simulator()
+        .record_path(true)
+        .run(&[Input { x: 0.0, hold: true }, Input { x: 200.0, hold: false }]);
+
+    assert!(result.survived());
+    assert!(result.path.iter().all(|&(_, y)| y >= 15.0));
+}
+
+#[test]
+fn simulate_short_click_on_orb() {
+    let mut level = spikes(false);
+
+    level.meta.starting_game_mode = GameMode::Ship;
+    level.objects.push(LevelObject {
+        metadata: ObjectData::Orb {
+            orb: Orb::Yellow,
+            multi_activate: false,
+        },
+        ..LevelObject::new(ids::YELLOW_ORB, 195.0, 15.0)
+    });
+
+    assert!(!level.simulator().run(&[]).survived());
+
+    // the button is pressed and released within a single tick, which still activates the orb
+    let result = level.simulator().record_path(true).run(&Input::click(190.0, 0.1));
+
+    assert!(result.survived());
+    assert!(result.path.iter().any(|&(_, y)| y > 45.0));
+}
@@ -0,0 +1,82 @@
+use dash_rs::model::level::{
+    object::{speed::Speed, ObjectCategory},
+    stats::BoundingBox,
+};
+use framework::objects;
+
+mod framework;
+
+#[test]
+fn level_stats() {
+    let objects = objects(&[
+        // blocks, one of which is in groups 1 and 2 and uses color channel 3
+        "1,1,2,15,3,15,57,1.2,21,3",
+        "1,1,2,45,3,15",
+        // spike
+        "1,8,2,75,3,15,57,2",
+        // yellow orb
+        "1,36,2,105,3,75",
+        // secret coin
+        "1,142,2,135,3,105",
+        // dual portal
+        "1,286,2,165,3,45",
+        // color trigger for channel 4
+        "1,899,2,195,3,165,23,4,7,255,8,0,9,0,10,0.5,35,1",
+        // decoration
+        "1,1764,2,311.58,3,-15",
+    ]);
+
+    let stats = objects.stats();
+
+    assert_eq!(stats.object_count, 8);
+    assert_eq!(stats.objects_by_id[&1], 2);
+    assert_eq!(stats.objects_by_category[&ObjectCategory::Solid], 2);
+    assert_eq!(stats.objects_by_category[&ObjectCategory::Hazard], 1);
+    assert_eq!(stats.objects_by_category[&ObjectCategory::Interactive], 2);
+    assert_eq!(stats.objects_by_category[&ObjectCategory::Portal], 1);
+    assert_eq!(stats.objects_by_category[&ObjectCategory::Trigger], 1);
+    assert_eq!(stats.objects_by_category[&ObjectCategory::Decoration], 1);
+    assert_eq!(stats.distinct_groups, 2);
+    assert_eq!(stats.distinct_color_channels, 2);
+    assert_eq!(
+        stats.bounding_box,
+        Some(BoundingBox {
+            min_x: 15.0,
+            min_y: -15.0,
+            max_x: 311.58,
+            max_y: 165.0
+        })
+    );
+    assert_eq!(objects.bounding_box(), stats.bounding_box);
+    assert_eq!(framework::objects(&[]).bounding_box(), None);
+    assert_eq!(stats.secret_coins, 1);
+    assert_eq!(stats.orbs, 1);
+    assert_eq!(stats.pads, 0);
+    assert!(stats.has_dual);
+    assert!(!stats.two_player);
+
+    let normal: f32 = Speed::Normal.into();
+
+    assert!((stats.length_in_seconds - 311.58 / normal).abs() < 1e-4);
+    assert!((stats.objects_per_second - 8.0 / stats.length_in_seconds).abs() < 1e-4);
+}
+
+#[test]
+fn orbs_and_pads() {
+    let objects = objects(&[
+        // yellow orb
+        "1,36,2,15,3,15",
+        // green dash orb
+        "1,1704,2,45,3,15",
+        // toggle orb
+        "1,1594,2,75,3,15",
+        // yellow pad
+        "1,35,2,105,3,15",
+    ]);
+
+    let stats = objects.stats();
+
+    assert_eq!(stats.orbs, 3);
+    assert_eq!(stats.orbs, objects.click_points().len());
+    assert_eq!(stats.pads, 1);
+}
@@ -0,0 +1,82 @@
+use base64::{engine::general_purpose::URL_SAFE, Engine};
+use dash_rs::{
+    model::level::{object::ObjectData, stream::ObjectStream, Level},
+    GJFormat, Thunk,
+};
+use std::path::Path;
+
+fn load_level(id: &str) -> String {
+    std::fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("artifacts")
+            .join("level")
+            .join(id)
+            .join("raw"),
+    )
+    .unwrap()
+}
+
+#[test]
+fn stream_matches_processed_objects() {
+    for id in ["897837", "11774780"] {
+        let raw = load_level(id);
+        let mut level: Level = Level::from_gj_str(&raw).unwrap();
+
+        let encoded = match &level.level_data.level_data {
+            Thunk::Unprocessed(encoded) => encoded.to_string(),
+            Thunk::Processed(_) => unreachable!(),
+        };
+        let objects = level.level_data.level_data.process().unwrap();
+
+        let stream = ObjectStream::new(&encoded).unwrap();
+
+        assert_eq!(stream.meta(), &objects.meta);
+        assert!(stream.map(Result::unwrap).eq(objects.objects.iter().cloned()));
+    }
+}
+
+#[test]
+fn stream_stops_early() {
+    let raw = load_level("11774780");
+    let level: Level = Level::from_gj_str(&raw).unwrap();
+    let encoded = level.level_data.level_data.as_unprocessed().unwrap();
+
+    let mut stream = ObjectStream::new(&encoded).unwrap();
+
+    let first_portal = stream
+        .by_ref()
+        .map(Result::unwrap)
+        .position(|object| matches!(object.metadata, ObjectData::SpeedPortal { .. }))
+        .unwrap();
+
+    // the stream can be resumed after stopping, and only yields the objects not yet consumed
+    let mut remaining = 0;
+
+    while let Some(object) = stream.next_raw() {
+        assert!(!object.unwrap().is_empty());
+        remaining += 1;
+    }
+
+    assert_eq!(first_portal + 1 + remaining, ObjectStream::new(&encoded).unwrap().count());
+}
+
+#[test]
+fn stream_ends_after_corrupt_data() {
+    let raw = load_level("11774780");
+    let level: Level = Level::from_gj_str(&raw).unwrap();
+    let encoded = level.level_data.level_data.as_unprocessed().unwrap();
+
+    let mut compressed = URL_SAFE.decode(encoded.as_bytes()).unwrap();
+    compressed.truncate(compressed.len() / 2);
+    let truncated = URL_SAFE.encode(compressed);
+
+    let mut stream = ObjectStream::new(&truncated).unwrap();
+    let results: Vec<_> = stream.by_ref().collect();
+
+    assert!(!results.is_empty());
+    assert!(results[..results.len() - 1].iter().all(Result::is_ok));
+    assert!(results.last().unwrap().is_err());
+    assert!(stream.next().is_none());
+    assert!(stream.next_raw().is_none());
+}
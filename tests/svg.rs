@@ -0,0 +1,51 @@
+use dash_rs::model::level::svg::{ColorMode, SvgOptions};
+use framework::objects;
+
+mod framework;
+
+#[test]
+fn svg_export() {
+    let level = objects(&[
+        // block, rotated by 90 degrees and flipped horizontally
+        "1,1,2,15,3,15,6,90,4,1",
+        // spike
+        "1,8,2,45,3,15",
+        // ship portal
+        "1,13,2,75,3,45",
+        // color trigger
+        "1,899,2,105,3,165,23,4,7,255,8,0,9,0,10,0.5,35,1",
+    ]);
+
+    let svg = level.to_svg(&SvgOptions::default());
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert!(svg.contains(r#"<g transform="translate(15,15) rotate(-90) scale(-1,1)">"#));
+    assert!(svg.contains(r#"<g transform="translate(45,15) rotate(-0) scale(1,1)"><path"#));
+    assert!(!svg.contains("translate(105,165)"));
+    assert!(svg.contains("<line x1=\"75\""));
+
+    let svg = level.to_svg(&SvgOptions {
+        triggers: true,
+        portal_overlay: false,
+        game_mode_overlay: true,
+        color_mode: ColorMode::ColorChannel,
+        ..SvgOptions::default()
+    });
+
+    assert!(svg.contains("translate(105,165)"));
+    assert!(!svg.contains("<line"));
+    // cube section, then ship section
+    assert!(svg.contains(r##"fill="#3b82f6" fill-opacity="0.2""##));
+    assert!(svg.contains(r##"fill="#ec4899" fill-opacity="0.2""##));
+}
+
+#[test]
+fn svg_export_empty_level() {
+    let svg = objects(&[]).to_svg(&SvgOptions {
+        width: Some(100.0),
+        ..SvgOptions::default()
+    });
+
+    assert!(svg.contains(r#"width="100" height="100" viewBox="0 -30 30 30""#));
+}
@@ -0,0 +1,103 @@
+use dash_rs::{
+    model::level::{metadata::LevelMetadata, object::speed::Speed, Level, LevelLength},
+    GJFormat,
+};
+use framework::{objects, objects_with_meta};
+use std::path::Path;
+
+mod framework;
+
+#[test]
+fn timeline_conversions() {
+    let objects = objects(&[
+        // block at the end of the level
+        "1,1,2,3000,3,15",
+        // medium speed portal
+        "1,202,2,1000,3,45",
+        // slow speed portal
+        "1,200,2,2000,3,45",
+    ]);
+    let timeline = objects.timeline();
+
+    let normal: f32 = Speed::Normal.into();
+    let medium: f32 = Speed::Medium.into();
+    let slow: f32 = Speed::Slow.into();
+
+    let at_slow_portal = 1000.0 / normal + 1000.0 / medium;
+
+    assert_eq!(timeline.speed_at(1500.0), Speed::Medium);
+    assert!((timeline.seconds_at(500.0) - 500.0 / normal).abs() < 1e-4);
+    assert!((timeline.seconds_at(2000.0) - at_slow_portal).abs() < 1e-4);
+    assert!((timeline.length_in_seconds() - (at_slow_portal + 1000.0 / slow)).abs() < 1e-4);
+
+    for x in [0.0, 250.0, 1000.0, 1750.0, 2500.0, 3000.0] {
+        assert!((timeline.x_at(timeline.seconds_at(x)) - x).abs() < 1e-2);
+    }
+
+    assert_eq!(timeline.level_length(), LevelLength::Tiny);
+}
+
+#[test]
+fn timeline_song_offset_and_platformer() {
+    let meta = LevelMetadata {
+        song_offset: 2.5,
+        platformer: true,
+        ..LevelMetadata::default()
+    };
+    let objects = objects_with_meta(meta, &["1,1,2,311.58,3,15"]);
+    let timeline = objects.timeline();
+
+    assert!((timeline.song_position_at(311.58) - 3.5).abs() < 1e-4);
+    assert_eq!(timeline.level_length(), LevelLength::Platformer);
+}
+
+#[test]
+fn timeline_matches_server_length() {
+    for id in ["897837", "11774780"] {
+        let raw = std::fs::read_to_string(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("artifacts")
+                .join("level")
+                .join(id)
+                .join("raw"),
+        )
+        .unwrap();
+        let mut level: Level = Level::from_gj_str(&raw).unwrap();
+        let objects = level.level_data.level_data.process().unwrap();
+
+        assert_eq!(objects.timeline().level_length(), level.length, "level {}", id);
+    }
+}
+
+#[test]
+fn click_points() {
+    let objects = objects(&[
+        "1,1,2,3000,3,15",
+        // yellow orbs
+        "1,36,2,1200,3,45",
+        "1,36,2,311.58,3,45",
+        // green dash orb
+        "1,1704,2,1300,3,45",
+        // yellow pad, which does not require a click
+        "1,35,2,400,3,15",
+        // medium speed portal
+        "1,202,2,1000,3,45",
+        // toggle orb
+        "1,1594,2,2000,3,45",
+    ]);
+    let clicks = objects.click_points();
+
+    assert_eq!(clicks.iter().map(|click| click.index).collect::<Vec<_>>(), vec![2, 1, 3, 6]);
+    assert!((clicks[0].seconds - 1.0).abs() < 1e-4);
+    assert_eq!(clicks[1].seconds, objects.timeline().seconds_at(1200.0));
+
+    // the second and third orb are about a quarter of a second apart
+    assert_eq!(objects.peak_clicks_per_second(1.0), 2.0);
+    assert_eq!(objects.peak_clicks_per_second(0.1), 10.0);
+
+    // degenerate windows
+    assert_eq!(objects.peak_clicks_per_second(0.0), 0.0);
+    assert_eq!(objects.peak_clicks_per_second(-1.0), 0.0);
+    assert_eq!(objects.peak_clicks_per_second(f32::NAN), 0.0);
+}
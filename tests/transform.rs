@@ -0,0 +1,138 @@
+use dash_rs::model::level::{
+    object::{LevelObject, ObjectData},
+    selection::Selection,
+    Objects,
+};
+use framework::objects;
+
+mod framework;
+
+fn level() -> Objects {
+    objects(&[
+        // block rotated by 30 degrees
+        "1,1,2,45,3,15,6,30",
+        // move trigger moving group 1 two blocks right and one block up
+        "1,901,2,105,3,45,51,1,10,0.5,28,60,29,30,30,0",
+        // rotate trigger rotating group 1 by 90 degrees
+        "1,1346,2,135,3,45,51,1,71,2,68,90,69,1",
+        // teleport portal moving the player up by 90 units
+        "1,747,2,165,3,45,54,90",
+        // block that is not part of the selection
+        "1,1,2,315,3,15",
+    ])
+}
+
+fn move_offset(object: &LevelObject) -> (f32, f32) {
+    match object.metadata {
+        ObjectData::MoveTrigger(ref trigger) => (trigger.offset_x, trigger.offset_y),
+        ref metadata => panic!("unexpected metadata {:?}", metadata),
+    }
+}
+
+#[test]
+fn translate() {
+    let mut objects = level();
+    let selection = Selection::new(0..4);
+
+    objects.translate(&selection, 30.0, -15.0);
+
+    assert_eq!((objects.objects[0].x, objects.objects[0].y), (75.0, 0.0));
+    assert_eq!((objects.objects[4].x, objects.objects[4].y), (315.0, 15.0));
+    assert_eq!(move_offset(&objects.objects[1]), (60.0, 30.0));
+}
+
+#[test]
+fn rotate_quarter_turn() {
+    let mut objects = level();
+    let selection = Selection::new(0..2);
+
+    objects.rotate(&selection, 90.0, (105.0, 15.0));
+
+    // rotating clockwise around (105, 15) moves the block from the left of the pivot to above it
+    assert_eq!((objects.objects[0].x, objects.objects[0].y), (105.0, 75.0));
+    assert_eq!(objects.objects[0].rotation, 120.0);
+    assert_eq!((objects.objects[1].x, objects.objects[1].y), (135.0, 15.0));
+    assert_eq!(move_offset(&objects.objects[1]), (30.0, -60.0));
+}
+
+#[test]
+fn rotate_counter_clockwise() {
+    // block rotated by 30 degrees and camera offset trigger moving the camera two blocks right
+    let mut objects = objects(&["1,1,2,45,3,15,6,30", "1,1916,2,105,3,45,28,60,29,0,10,2"]);
+    let selection = objects.select(|_| true);
+
+    objects.rotate(&selection, -90.0, (105.0, 15.0));
+
+    assert_eq!(objects.objects[0].rotation, 300.0);
+
+    match objects.objects[1].metadata {
+        ObjectData::CameraOffsetTrigger(ref trigger) => assert_eq!((trigger.offset_x, trigger.offset_y), (0.0, 60.0)),
+        ref metadata => panic!("unexpected metadata {:?}", metadata),
+    }
+}
+
+#[test]
+fn scale() {
+    let mut objects = level();
+    let selection = Selection::new(0..4);
+
+    objects.scale(&selection, 2.0, (15.0, 15.0));
+
+    assert_eq!((objects.objects[0].x, objects.objects[0].y), (75.0, 15.0));
+    assert_eq!(objects.objects[0].scale, 2.0);
+    assert_eq!(move_offset(&objects.objects[1]), (120.0, 60.0));
+    assert_eq!(objects.objects[3].metadata, ObjectData::TeleportPortal { offset_y: 180.0 });
+}
+
+#[test]
+fn mirror() {
+    let mut objects = level();
+    let selection = Selection::new(0..4);
+
+    objects.mirror_horizontally(&selection, 0.0);
+
+    let block = &objects.objects[0];
+    assert_eq!(
+        (block.x, block.y, block.rotation, block.flipped_x, block.flipped_y),
+        (-45.0, 15.0, 330.0, true, false)
+    );
+    assert_eq!(move_offset(&objects.objects[1]), (-60.0, 30.0));
+
+    match objects.objects[2].metadata {
+        ObjectData::RotateTrigger(ref trigger) => assert_eq!((trigger.degrees, trigger.full_rotations), (-90.0, -1)),
+        ref metadata => panic!("unexpected metadata {:?}", metadata),
+    }
+
+    objects.mirror_vertically(&selection, 0.0);
+
+    let block = &objects.objects[0];
+    assert_eq!(
+        (block.x, block.y, block.rotation, block.flipped_x, block.flipped_y),
+        (-45.0, -15.0, 30.0, true, true)
+    );
+    assert_eq!(move_offset(&objects.objects[1]), (-60.0, -30.0));
+    assert_eq!(objects.objects[3].metadata, ObjectData::TeleportPortal { offset_y: -90.0 });
+}
+
+#[test]
+fn align_to_grid() {
+    let mut objects = objects(&["1,1,2,52,3,8", "1,1,2,-20,3,100"]);
+    let selection = objects.select(|_| true);
+
+    objects.align_to_grid(&selection);
+
+    assert_eq!((objects.objects[0].x, objects.objects[0].y), (45.0, 15.0));
+    assert_eq!((objects.objects[1].x, objects.objects[1].y), (-15.0, 105.0));
+}
+
+#[test]
+fn rotate_around_selection_center() {
+    let mut objects = objects(&["1,1,2,15,3,15", "1,1,2,75,3,15"]);
+    let selection = objects.select(|_| true);
+    let center = selection.bounding_box(&objects).unwrap().center();
+
+    objects.rotate(&selection, 90.0, center);
+
+    assert_eq!((objects.objects[0].x, objects.objects[0].y), (45.0, 45.0));
+    assert_eq!((objects.objects[1].x, objects.objects[1].y), (45.0, -15.0));
+}
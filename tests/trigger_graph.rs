@@ -0,0 +1,42 @@
+use dash_rs::model::level::object::trigger::{ItemKind, ItemReference};
+use framework::objects;
+
+mod framework;
+
+#[test]
+fn trigger_graph() {
+    let objects = objects(&[
+        // block
+        "1,1,2,15,3,15",
+        // spawn trigger activating group 5
+        "1,1268,2,45,3,15,51,5,63,0.5",
+        // spawn triggered move trigger in group 5
+        "1,901,2,75,3,15,57,5,62,1,51,7,10,0.5,28,30,29,0,30,0",
+        // spawn triggered toggle trigger in group 9, which is never activated
+        "1,1049,2,105,3,15,57,9,62,1,51,10,56,1",
+        // pickup trigger adding one to item 3
+        "1,1817,2,135,3,15,80,3,77,1",
+        // count trigger activating group 11 once item 3 reaches 1
+        "1,1611,2,165,3,15,80,3,77,1,51,11,56,1",
+    ]);
+
+    let graph = objects.trigger_graph();
+
+    assert_eq!(graph.nodes.len(), 5);
+    assert_eq!(graph.nodes.iter().map(|node| node.index).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+
+    assert_eq!(graph.activators(5).map(|node| node.index).collect::<Vec<_>>(), vec![1]);
+    assert_eq!(graph.members(5).map(|node| node.index).collect::<Vec<_>>(), vec![2]);
+    assert_eq!(graph.nodes[1].targets, vec![7]);
+
+    let item = ItemReference {
+        id: 3,
+        kind: ItemKind::Item,
+    };
+
+    assert_eq!(graph.writers(item).map(|node| node.index).collect::<Vec<_>>(), vec![4]);
+    assert_eq!(graph.readers(item).map(|node| node.index).collect::<Vec<_>>(), vec![5]);
+
+    assert_eq!(graph.dead_triggers(), vec![3]);
+    assert_eq!(graph.unreachable_groups().into_iter().collect::<Vec<_>>(), vec![10]);
+}